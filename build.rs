@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use toml::Table;
+
+const INPUTS_DIR: &str = "inputs";
+const ANSWERS_FILE: &str = "answers.toml";
+
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={INPUTS_DIR}");
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let inputs = root.join(INPUTS_DIR);
+
+    fs::write(out.join("fixtures.rs"), fixture_tests(&inputs)?)?;
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        fs::write(out.join("inputs.rs"), embedded_inputs(&inputs)?)?;
+    }
+    Ok(())
+}
+
+/// A test per compiled day, input and part, checked against the year's
+/// answers. Parts without an answer or an input, or listed under the day's
+/// `ignore` table, give ignored tests saying why.
+fn fixture_tests(inputs: &Path) -> io::Result<String> {
+    let mut years: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+    for (var, _) in env::vars() {
+        if let Some((year, day)) = day_feature(&var) {
+            years.entry(year).or_default().push(day);
+        }
+    }
+
+    let mut code = String::new();
+    for (year, mut days) in years {
+        days.sort();
+        let answers = read_answers(&inputs.join(year.to_string()))?;
+
+        code += &format!("mod y{year} {{\n");
+        for day in days {
+            code += &format!("    mod d{day:02} {{\n");
+            for kind in ["example", "input"] {
+                for part in [1, 2] {
+                    code += "        #[test]\n";
+                    let reason =
+                        ignore_reason(inputs, &answers, year, day, kind, part);
+                    if let Some(reason) = reason {
+                        code += &format!("        #[ignore = {reason:?}]\n");
+                    }
+                    code += &format!(
+                        "        fn part_{part}_{kind}() {{\n            \
+                         crate::fixtures::check({year}, {day}, {kind:?}, \
+                         {part});\n        }}\n"
+                    );
+                }
+            }
+            code += "    }\n";
+        }
+        code += "}\n";
+    }
+    Ok(code)
+}
+
+/// The year and day of a feature such as `y2025-d04`, as cargo passes it to
+/// build scripts.
+fn day_feature(var: &str) -> Option<(u16, u8)> {
+    let (year, day) = var.strip_prefix("CARGO_FEATURE_Y")?.split_once("_D")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn read_answers(year_dir: &Path) -> io::Result<Table> {
+    let path = year_dir.join(ANSWERS_FILE);
+    match fs::read_to_string(&path) {
+        Ok(raw) => raw
+            .parse()
+            .map_err(|e| io::Error::other(format!("{}: {e}", path.display()))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Table::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn ignore_reason(
+    inputs: &Path,
+    answers: &Table,
+    year: u16,
+    day: u8,
+    kind: &str,
+    part: u8,
+) -> Option<String> {
+    let day_key = format!("{day:02}");
+    let part_key = format!("part_{part}");
+    let entry = |table: &str| answers.get(&day_key)?.get(table)?.get(&part_key);
+
+    if let Some(reason) = entry("ignore") {
+        return Some(reason.as_str().unwrap_or("ignored").to_string());
+    }
+
+    let input = format!("{INPUTS_DIR}/{year}/{day_key}/{kind}.txt");
+    if !inputs.parent().unwrap().join(&input).exists() {
+        return Some(format!("{input} does not exist"));
+    }
+
+    if entry(kind).is_none() {
+        return Some(format!(
+            "no {part_key} answer for [{day_key}.{kind}] in \
+             {INPUTS_DIR}/{year}/{ANSWERS_FILE}"
+        ));
+    }
+    None
+}
+
+/// `FILES`, every file under `inputs` keyed by its path relative to it.
+fn embedded_inputs(inputs: &Path) -> io::Result<String> {
+    let mut files = Vec::new();
+    collect(inputs, &mut files)?;
+    files.sort();
+
+    let mut code = String::from("pub static FILES: &[(&str, &str)] = &[\n");
+    for file in files {
+        let relative = file.strip_prefix(inputs).unwrap();
+        let relative = relative.to_str().unwrap().replace('\\', "/");
+        code += &format!(
+            "    ({relative:?}, include_str!({:?})),\n",
+            file.to_str().unwrap()
+        );
+    }
+    code += "];\n";
+    Ok(code)
+}
+
+/// Every file under `dir`, recursively.
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
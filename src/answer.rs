@@ -0,0 +1,131 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+///
+/// Numbers compare by value, so `Signed(5) == Unsigned(5)`.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(s), Answer::Unsigned(u))
+            | (Answer::Unsigned(u), Answer::Signed(s)) => {
+                u128::try_from(*s).is_ok_and(|s| s == *u)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+// Only `i128` is compared directly so integer literals in tests infer it.
+impl PartialEq<i128> for Answer {
+    fn eq(&self, other: &i128) -> bool {
+        *self == Answer::Signed(*other)
+    }
+}
+
+impl PartialEq<Answer> for i128 {
+    fn eq(&self, other: &Answer) -> bool {
+        Answer::Signed(*self) == *other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Reads numbers as numbers and anything else as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        let s = s.trim();
+        Ok(if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident, $wide:ty, $($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::$variant(n as $wide)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+from_integer!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::from(5u64), Answer::from(5i32));
+        assert_ne!(Answer::from(u128::MAX), Answer::from(-1i8));
+        assert_ne!(Answer::from(5u8), Answer::from("5"));
+        assert_eq!(15118009521693, Answer::from(15118009521693usize));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("-42", Answer::from(-42i64).to_string());
+        assert_eq!(
+            "340282366920938463463374607431768211455",
+            Answer::from(u128::MAX).to_string()
+        );
+        assert_eq!("abc", Answer::from("abc").to_string());
+    }
+
+    #[test]
+    fn parse() {
+        let parse = |s: &str| s.parse::<Answer>().unwrap();
+        assert_eq!(Answer::Unsigned(1018), parse("1018\n"));
+        assert_eq!(Answer::Signed(-7), parse("-7"));
+        assert_eq!(Answer::Text("LHBFQ".to_string()), parse(" LHBFQ "));
+    }
+}
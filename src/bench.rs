@@ -0,0 +1,136 @@
+use serde::Serialize;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// What a timing covers: parsing the input or solving one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl Stage {
+    pub fn part(part: u8) -> Self {
+        match part {
+            1 => Stage::Part1,
+            2 => Stage::Part2,
+            _ => panic!("no stage for part {part}"),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part1 => f.pad("part 1"),
+            Stage::Part2 => f.pad("part 2"),
+        }
+    }
+}
+
+/// Summary of repeated timings of the same work, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty. The median of an even
+    /// number of samples is the lower of the two middle ones.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+
+        let n = samples.len();
+        let p95_rank = (n * 95).div_ceil(100);
+        Stats {
+            iterations: n,
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[(n - 1) / 2].as_nanos(),
+            p95_ns: samples[p95_rank - 1].as_nanos(),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        nanos(self.median_ns)
+    }
+
+    pub fn p95(&self) -> Duration {
+        nanos(self.p95_ns)
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T>(
+    warmup: usize,
+    iterations: usize,
+    mut f: impl FnMut() -> T,
+) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+fn nanos(ns: u128) -> Duration {
+    Duration::from_nanos(ns.try_into().unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(5, stats.iterations);
+        assert_eq!(Duration::from_millis(1), stats.min());
+        assert_eq!(Duration::from_millis(3), stats.median());
+        assert_eq!(Duration::from_millis(5), stats.p95());
+    }
+
+    #[test]
+    fn p95_of_many_samples() {
+        let samples: Vec<u64> = (1..=40).rev().collect();
+        let stats = Stats::from_samples(millis(&samples));
+        assert_eq!(Duration::from_millis(20), stats.median());
+        assert_eq!(Duration::from_millis(38), stats.p95());
+    }
+
+    #[test]
+    fn measure_runs_every_iteration() {
+        let mut calls = 0;
+        let stats = measure(2, 5, || calls += 1);
+        assert_eq!(7, calls);
+        assert_eq!(5, stats.iterations);
+    }
+}
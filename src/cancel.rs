@@ -0,0 +1,88 @@
+//! Cooperative cancellation of long running parts. The runner gives each part
+//! it may give up on a `Token`, and long loops in solutions call `checkpoint`
+//! so they stop soon after it is cancelled instead of running on unobserved.
+
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+use std::sync::Arc;
+
+/// Shared flag telling a run to stop. Clones cancel together.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+/// What a cancelled part unwinds with.
+#[derive(Debug)]
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Runs `f` with `token` as the current thread's token.
+pub fn with_token<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    CURRENT.set(previous);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Whether the current thread's token was cancelled. Without a token,
+/// never.
+pub fn is_cancelled() -> bool {
+    CURRENT.with_borrow(|token| token.as_ref().is_some_and(Token::is_cancelled))
+}
+
+/// Unwinds with `Cancelled` if the current thread's token was cancelled,
+/// without going through the panic hook.
+pub fn checkpoint() {
+    if is_cancelled() {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checkpoint_stops_cancelled_loop() {
+        let token = Token::new();
+        let result = panic::catch_unwind(|| {
+            with_token(token.clone(), || {
+                for i in 0.. {
+                    checkpoint();
+                    if i == 10 {
+                        token.cancel();
+                    }
+                }
+            })
+        });
+        assert!(result.unwrap_err().is::<Cancelled>());
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    fn no_token_never_cancelled() {
+        Token::new().cancel();
+        checkpoint();
+        assert!(!is_cancelled());
+    }
+}
@@ -0,0 +1,332 @@
+use clap::{
+    Args,
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Advent of code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Directory holding the inputs, overrides `AOC_INPUT_DIR` and
+    /// `aoc.toml`
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+
+    /// Event year, the latest registered one by default
+    #[arg(short, long, global = true)]
+    pub year: Option<u16>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run one day, or every day with --all
+    Run(RunArgs),
+    /// Check every registered day against the expected answers
+    Verify(VerifyArgs),
+    /// Time parsing and each part separately
+    Bench(BenchArgs),
+    /// Create a new day from the template and register it
+    NewDay(NewDayArgs),
+    /// Download a day's input unless it is already cached
+    Fetch(FetchArgs),
+    /// Solve one part against the real input and submit the answer
+    Submit(SubmitArgs),
+    /// Verify one day again whenever its source or inputs change
+    Watch(WatchArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// Day to run
+    #[arg(required_unless_present = "all")]
+    pub day: Option<u8>,
+
+    /// Part to run, both parts if omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Run every registered day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    pub all: bool,
+
+    /// Puzzle input file, `-` reads it from stdin
+    #[arg(short, long, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Use the day's example input instead of the real one
+    #[arg(short, long)]
+    pub example: bool,
+
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Run one day at a time instead of in parallel, for clean timings
+    #[arg(long)]
+    pub sequential: bool,
+
+    /// Give up on a part still running after this many seconds
+    #[arg(long, value_parser = seconds)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only verify this day
+    pub day: Option<u8>,
+
+    /// Answers manifest, `inputs/<year>/answers.toml` by default
+    #[arg(long)]
+    pub answers: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Verify one day at a time instead of in parallel
+    #[arg(long)]
+    pub sequential: bool,
+
+    /// Give up on a part still running after this many seconds
+    #[arg(long, value_parser = seconds)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Only benchmark this day
+    pub day: Option<u8>,
+
+    /// Use the days' example inputs instead of the real ones
+    #[arg(short, long)]
+    pub example: bool,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 1)]
+    pub warmup: usize,
+
+    /// Timed runs per stage
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub iterations: usize,
+
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+#[derive(Debug, Args)]
+pub struct NewDayArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+}
+
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+    /// Day to download
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Site to download from, overrides `AOC_BASE_URL` and `aoc.toml`
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// Day to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Part to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// Submit even a part answers.toml ignores or the template's "unsolved"
+    #[arg(long)]
+    pub force: bool,
+
+    /// Site to submit to, overrides `AOC_BASE_URL` and `aoc.toml`
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Milliseconds between looks at the files
+    #[arg(long, default_value_t = 500)]
+    pub interval: u64,
+}
+
+/// How `run`, `verify` and `bench` print their results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table for people to read
+    Text,
+    /// An array of records
+    Json,
+    /// One record per row, under a header
+    Csv,
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        RunArgs {
+            day: None,
+            part: None,
+            all: true,
+            input: None,
+            example: false,
+            format: Format::Text,
+            sequential: false,
+            timeout: None,
+        }
+    }
+}
+
+fn seconds(arg: &str) -> Result<Duration, String> {
+    arg.parse()
+        .ok()
+        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+        .ok_or_else(|| format!("`{arg}` is not a number of seconds"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> RunArgs {
+        let cli =
+            Cli::try_parse_from(["advent_of_code", "run"].iter().chain(args))
+                .unwrap();
+        match cli.command {
+            Some(Command::Run(args)) => args,
+            _ => panic!("expected the run command"),
+        }
+    }
+
+    #[test]
+    fn day_and_part() {
+        let args = run_args(&["7", "2"]);
+        assert_eq!(Some(7), args.day);
+        assert_eq!(Some(2), args.part);
+        assert!(!args.all);
+    }
+
+    #[test]
+    fn stdin_input() {
+        let args = run_args(&["3", "--input", "-"]);
+        assert_eq!(Some(PathBuf::from("-")), args.input);
+    }
+
+    #[test]
+    fn all_days() {
+        let args = run_args(&["--all", "--example", "--sequential"]);
+        assert!(args.all);
+        assert!(args.example);
+        assert!(args.sequential);
+        assert_eq!(None, args.day);
+        assert_eq!(Format::Text, args.format);
+    }
+
+    #[test]
+    fn verify_one_day() {
+        let cli = Cli::try_parse_from([
+            "advent_of_code",
+            "verify",
+            "5",
+            "--format",
+            "csv",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Verify(args)) => {
+                assert_eq!(Some(5), args.day);
+                assert_eq!(Format::Csv, args.format);
+            }
+            _ => panic!("expected the verify command"),
+        }
+    }
+
+    #[test]
+    fn year_after_subcommand() {
+        let cli = Cli::try_parse_from([
+            "advent_of_code",
+            "run",
+            "3",
+            "--year",
+            "2024",
+        ])
+        .unwrap();
+        assert_eq!(Some(2024), cli.year);
+
+        let cli = Cli::try_parse_from(["advent_of_code", "verify"]).unwrap();
+        assert_eq!(None, cli.year);
+    }
+
+    #[test]
+    fn bench_options() {
+        let cli = Cli::try_parse_from([
+            "advent_of_code",
+            "bench",
+            "8",
+            "-n",
+            "50",
+            "--format",
+            "json",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Bench(args)) => {
+                assert_eq!(Some(8), args.day);
+                assert_eq!(50, args.iterations);
+                assert_eq!(1, args.warmup);
+                assert_eq!(Format::Json, args.format);
+            }
+            _ => panic!("expected the bench command"),
+        }
+    }
+
+    #[test]
+    fn timeout_in_seconds() {
+        let args = run_args(&["9", "--timeout", "1.5"]);
+        assert_eq!(Some(Duration::from_millis(1500)), args.timeout);
+        assert_eq!(None, run_args(&["9"]).timeout);
+    }
+
+    #[test]
+    fn watch_interval() {
+        let cli =
+            Cli::try_parse_from(["advent_of_code", "watch", "4"]).unwrap();
+        match cli.command {
+            Some(Command::Watch(args)) => {
+                assert_eq!(4, args.day);
+                assert_eq!(500, args.interval);
+            }
+            _ => panic!("expected the watch command"),
+        }
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        let parse = |args: &[&str]| {
+            Cli::try_parse_from(["advent_of_code", "run"].iter().chain(args))
+        };
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["1", "3"]).is_err());
+        assert!(parse(&["--all", "--input", "x.txt"]).is_err());
+        assert!(parse(&["1", "--input", "x.txt", "--example"]).is_err());
+        assert!(parse(&["1", "--timeout", "-1"]).is_err());
+        assert!(parse(&["1", "--timeout", "soon"]).is_err());
+        assert!(
+            Cli::try_parse_from(["advent_of_code", "new-day", "26"]).is_err()
+        );
+    }
+}
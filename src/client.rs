@@ -0,0 +1,351 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/jwllzp/advent_of_code)"
+);
+
+/// Talks to the Advent of Code site, or anything serving the same paths.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+/// Where `fetch` got an input from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cache,
+    Download,
+}
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission with.
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(wait_time(page).unwrap_or_default()))
+        } else if page.contains("solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::RateLimited(wait) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Parses "You have 1m 5s left to wait" from a rate limited response.
+fn wait_time(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let unit = amount.chars().last()?;
+        let n: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+        seconds += match unit {
+            'h' => n * 3600,
+            'm' => n * 60,
+            's' => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(year, day));
+        self.agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| Error::Http(format!("GET {url}: {e}")))
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Verdict> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let page = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([
+                ("level", part.to_string()),
+                ("answer", answer.to_string()),
+            ])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| Error::Http(format!("POST {url}: {e}")))?;
+
+        Verdict::from_page(&page).ok_or_else(|| {
+            Error::Http(format!("POST {url}: unrecognised response"))
+        })
+    }
+
+    /// Writes the input of `day` to `path` unless an earlier fetch already
+    /// did. An empty file, as left by `new-day`, does not count.
+    pub fn fetch(&self, year: u16, day: u8, path: &Path) -> Result<Fetched> {
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Fetched::Cache);
+        }
+
+        let input = self.input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, input)?;
+        Ok(Fetched::Download)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{
+        BufRead,
+        BufReader,
+        Read,
+        Write,
+    };
+    use std::net::TcpListener;
+    use std::thread::{
+        self,
+        JoinHandle,
+    };
+
+    /// A request as the stub server saw it.
+    #[derive(Debug)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|h| {
+                let (key, value) = h.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        }
+    }
+
+    /// Serves one response per entry of `responses`, in order, then stops.
+    /// Returns the base URL and a handle yielding the requests received.
+    pub fn serve(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end().to_string();
+                    if header.is_empty() {
+                        break;
+                    }
+                    headers.push(header);
+                }
+
+                let mut request = Request {
+                    line: line.trim_end().to_string(),
+                    headers,
+                    body: String::new(),
+                };
+                let length = request
+                    .header("Content-Length")
+                    .map_or(0, |n| n.parse().unwrap());
+                let mut received = vec![0; length];
+                reader.read_exact(&mut received).unwrap();
+                request.body = String::from_utf8(received).unwrap();
+
+                write!(
+                    &stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir()
+            .join(format!("advent_of_code_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn download_input() {
+        let (url, server) = stub::serve(vec![(200, "1,2\n3,4\n")]);
+        let client = Client::new(&url, "secret");
+        assert_eq!("1,2\n3,4\n", client.input(2025, 9).unwrap());
+
+        let requests = server.join().unwrap();
+        assert_eq!("GET /2025/day/9/input HTTP/1.1", requests[0].line);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+        assert_eq!(Some(USER_AGENT), requests[0].header("user-agent"));
+    }
+
+    #[test]
+    fn fetch_only_once() {
+        let path = temp_path("fetch").join("input.txt");
+        let (url, server) = stub::serve(vec![(200, "abc\n")]);
+        let client = Client::new(&url, "secret");
+
+        assert_eq!(Fetched::Download, client.fetch(2024, 3, &path).unwrap());
+        assert_eq!(Fetched::Cache, client.fetch(2024, 3, &path).unwrap());
+        assert_eq!("abc\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(1, server.join().unwrap().len());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn classify_pages() {
+        let verdict = |page| Verdict::from_page(page).unwrap();
+        assert_eq!(
+            Verdict::Correct,
+            verdict("<p>That's the right answer! You are one gold star")
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            verdict("That's not the right answer; your answer is too high.")
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            verdict("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            verdict("That's not the right answer. If you're stuck")
+        );
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(65)),
+            verdict(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )
+        );
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(37)),
+            verdict(
+                "You gave an answer too recently. You have 37s left to wait."
+            )
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            verdict("You don't seem to be solving the right level.")
+        );
+        assert_eq!(None, Verdict::from_page("<html>Advent of Code</html>"));
+    }
+
+    #[test]
+    fn submit_answer() {
+        let (url, server) =
+            stub::serve(vec![(200, "<p>That's the right answer!</p>")]);
+        let client = Client::new(&url, "secret");
+        let verdict =
+            client.submit(2025, 4, 2, &Answer::from(8580u64)).unwrap();
+        assert_eq!(Verdict::Correct, verdict);
+
+        let requests = server.join().unwrap();
+        assert_eq!("POST /2025/day/4/answer HTTP/1.1", requests[0].line);
+        assert_eq!("level=2&answer=8580", requests[0].body);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+    }
+
+    #[test]
+    fn http_error() {
+        let (url, _server) = stub::serve(vec![(400, "Please log in")]);
+        let path = temp_path("rejected");
+        let error = Client::new(&url, "bad").fetch(2025, 1, &path).unwrap_err();
+        assert!(error.to_string().contains("/2025/day/1/input"));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::error::{
+    Error,
+    Result,
+};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Local settings file, kept out of git because it holds the session token.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Settings from `aoc.toml`, each of which the environment can override.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// The settings file at the crate root, wherever the binary runs from.
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE)
+    }
+
+    /// Reads `path`, which may be missing, then applies the environment.
+    pub fn load(path: &Path) -> Result<Self> {
+        let config = match fs::read_to_string(path) {
+            Ok(raw) => toml::from_str(&raw).map_err(|e| {
+                Error::Config(format!("{}: {}", path.display(), e.message()))
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(config.with_env(|var| std::env::var(var).ok()))
+    }
+
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(session) = var(SESSION_VAR) {
+            self.session = Some(session);
+        }
+        if let Some(base_url) = var(BASE_URL_VAR) {
+            self.base_url = Some(base_url);
+        }
+        if let Some(input_dir) = var(INPUT_DIR_VAR) {
+            self.input_dir = Some(input_dir.into());
+        }
+        self
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().map(str::trim).ok_or_else(|| {
+            Error::Config(format!(
+                "no session token, set {SESSION_VAR} or `session` in \
+                 {CONFIG_FILE}"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn environment_overrides_file() {
+        let config: Config =
+            toml::from_str("session = \"abc\"\ninput_dir = \"in\"").unwrap();
+        let config = config.with_env(|var| {
+            (var == BASE_URL_VAR).then(|| "http://localhost:1".to_string())
+        });
+        assert_eq!("abc", config.session().unwrap());
+        assert_eq!(Some("http://localhost:1"), config.base_url.as_deref());
+        assert_eq!(Some(Path::new("in")), config.input_dir.as_deref());
+
+        let config = config.with_env(|_| Some("xyz".to_string()));
+        assert_eq!("xyz", config.session().unwrap());
+        assert_eq!(Some(Path::new("xyz")), config.input_dir.as_deref());
+    }
+
+    #[test]
+    fn missing_session() {
+        let error = Config::default().session().unwrap_err();
+        assert_eq!(
+            "no session token, set AOC_SESSION or `session` in aoc.toml",
+            error.to_string()
+        );
+    }
+}
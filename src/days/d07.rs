@@ -1,408 +0,0 @@
-use std::collections::{
-    BTreeMap,
-    HashSet,
-};
-use std::fmt;
-use std::fs;
-
-#[derive(Debug)]
-pub struct TachyonManifold {
-    diagram: Vec<Vec<Obstacle>>,
-    beams: Vec<Beam>,
-}
-
-#[derive(Debug)]
-pub struct Graph {
-    nodes: BTreeMap<usize, Node>,
-    pub initial_node_id: usize,
-    terminal_node_ids: Vec<usize>,
-}
-
-#[derive(Clone, Debug)]
-enum Obstacle {
-    Space,
-    Splitter,
-}
-
-#[derive(Clone, Debug)]
-pub enum Node {
-    Initial(InitialNode),
-    Splitter(SplitterNode),
-    End(TerminalNode),
-}
-
-#[derive(Clone, Debug)]
-pub struct InitialNode {
-    id: usize,
-    child: usize,
-    acum_value: usize,
-}
-
-#[derive(Clone, Debug)]
-pub struct SplitterNode {
-    id: usize,
-    children: Vec<usize>,
-    acum_value: usize,
-}
-
-#[derive(Clone, Debug)]
-pub struct TerminalNode {
-    id: usize,
-    acum_value: usize,
-}
-
-#[derive(Debug)]
-struct Beam {
-    position: usize,
-}
-
-impl TachyonManifold {
-    pub fn new(path: &str) -> Self {
-        let raw = fs::read_to_string(path).unwrap();
-        let mut lines = raw.trim().lines();
-        let first_line = lines.next().unwrap();
-        let beam_start = first_line.find("S").unwrap();
-
-        let diagram: Vec<Vec<Obstacle>> = lines
-            .map(|l| {
-                l.chars()
-                    .map(|c| match c {
-                        '.' => Obstacle::Space,
-                        '^' => Obstacle::Splitter,
-                        _ => panic!(),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let beams = vec![Beam::new(beam_start)];
-
-        TachyonManifold { diagram, beams }
-    }
-
-    pub fn part_1(&self) -> usize {
-        let diagram_range = 0..self.diagram[0].len();
-        let mut splits = 0;
-        let mut beam_positions = self.get_beam_postions();
-        let mut next_beam_positions: HashSet<usize>;
-        let mut left: usize = 0;
-        let mut right: usize = 0;
-
-        for line in &self.diagram {
-            next_beam_positions = HashSet::new();
-            beam_positions.iter().for_each(|b| {
-                match line[*b] {
-                    Obstacle::Space => {
-                        next_beam_positions.insert(*b);
-                    }
-                    Obstacle::Splitter => {
-                        splits += 1;
-
-                        left = b - 1;
-                        right = b + 1;
-
-                        if diagram_range.contains(&left) {
-                            next_beam_positions.insert(left);
-                        }
-
-                        if diagram_range.contains(&right) {
-                            next_beam_positions.insert(right);
-                        }
-                    }
-                };
-            });
-            beam_positions = next_beam_positions;
-        }
-
-        splits
-    }
-
-    fn get_beam_postions(&self) -> HashSet<usize> {
-        self.beams.iter().map(|b| b.position).collect()
-    }
-}
-
-impl Beam {
-    fn new(position: usize) -> Self {
-        Beam { position }
-    }
-}
-
-impl fmt::Display for Obstacle {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Obstacle::Space => write!(f, "."),
-            Obstacle::Splitter => write!(f, "^"),
-        }
-    }
-}
-
-impl Graph {
-    pub fn new(path: &str) -> Self {
-        let raw = fs::read_to_string(path).unwrap();
-        let lines = raw.trim().lines();
-        let mut node_id = 0;
-        let mut diagram: Vec<Vec<Option<Node>>> = lines
-            .map(|l| {
-                l.chars()
-                    .map(|c| {
-                        let node = match c {
-                            'S' => {
-                                node_id += 1;
-                                Some(Node::Initial(InitialNode {
-                                    id: node_id,
-                                    child: 2,
-                                    acum_value: 0,
-                                }))
-                            }
-                            '^' => {
-                                node_id += 1;
-                                Some(Node::Splitter(SplitterNode {
-                                    id: node_id,
-                                    children: Vec::new(),
-                                    acum_value: 0,
-                                }))
-                            }
-                            _ => None,
-                        };
-                        node
-                    })
-                    .collect()
-            })
-            .collect();
-
-        let mut terminal_nodes = Vec::new();
-        for _ in 0..diagram[0].len() {
-            node_id += 1;
-            terminal_nodes.push(Some(Node::End(TerminalNode {
-                id: node_id,
-                acum_value: 1,
-            })));
-        }
-        diagram.push(terminal_nodes);
-
-        let mut nodes: BTreeMap<usize, Node> = BTreeMap::new();
-        let initial_node_id: usize = 1;
-        let mut terminal_node_ids: Vec<usize> = Vec::new();
-        for (row_idx, row) in diagram.iter().enumerate() {
-            for (col_idx, col) in row.iter().enumerate() {
-                if let Some(node) = col {
-                    match node {
-                        Node::Initial(initial_node) => {
-                            let mut initial_node = initial_node.clone();
-                            initial_node.child =
-                                Self::get_initial_node_child_ids(
-                                    &row_idx, &col_idx, &diagram,
-                                );
-                            nodes.insert(
-                                initial_node.id,
-                                Node::Initial(initial_node),
-                            );
-                        }
-                        Node::Splitter(splitter_node) => {
-                            let mut splitter_node = splitter_node.clone();
-                            splitter_node.children =
-                                Self::get_splitter_node_child_ids(
-                                    &row_idx, &col_idx, &diagram,
-                                );
-                            nodes.insert(
-                                splitter_node.id,
-                                Node::Splitter(splitter_node),
-                            );
-                        }
-                        Node::End(terminal_node) => {
-                            nodes.insert(
-                                terminal_node.id,
-                                Node::End(terminal_node.clone()),
-                            );
-                            terminal_node_ids.push(terminal_node.id);
-                        }
-                    }
-                }
-            }
-        }
-
-        Graph {
-            nodes,
-            initial_node_id,
-            terminal_node_ids,
-        }
-    }
-
-    fn get_initial_node_child_ids(
-        row_idx: &usize,
-        col_idx: &usize,
-        diagram: &Vec<Vec<Option<Node>>>,
-    ) -> usize {
-        let mut child_id: usize = 0;
-
-        for i in (row_idx + 1)..diagram.len() {
-            if let Some(child) = &diagram[i][*col_idx] {
-                match child {
-                    Node::Initial(_) => {}
-                    Node::Splitter(splitter_node) => {
-                        child_id = splitter_node.id;
-                    }
-                    Node::End(terminal_node) => {
-                        child_id = terminal_node.id;
-                    }
-                }
-                break;
-            }
-        }
-
-        child_id
-    }
-
-    fn get_splitter_node_child_ids(
-        row_idx: &usize,
-        col_idx: &usize,
-        diagram: &Vec<Vec<Option<Node>>>,
-    ) -> Vec<usize> {
-        let mut children: Vec<usize> = Vec::new();
-
-        for i in *row_idx..diagram.len() {
-            if let Some(child) = &diagram[i][col_idx - 1] {
-                match child {
-                    Node::Initial(_) => {}
-                    Node::Splitter(splitter_node) => {
-                        children.push(splitter_node.id);
-                    }
-                    Node::End(terminal_node) => {
-                        children.push(terminal_node.id);
-                    }
-                }
-                break;
-            }
-        }
-
-        for i in *row_idx..diagram.len() {
-            if let Some(child) = &diagram[i][col_idx + 1] {
-                match child {
-                    Node::Initial(_) => {}
-                    Node::Splitter(splitter_node) => {
-                        children.push(splitter_node.id);
-                    }
-                    Node::End(terminal_node) => {
-                        children.push(terminal_node.id);
-                    }
-                }
-                break;
-            }
-        }
-
-        children
-    }
-
-    pub fn propagate(&mut self, node_id: usize) {
-        let children = match self.nodes.get_mut(&node_id).unwrap() {
-            Node::Initial(initial_node) => vec![initial_node.child],
-            Node::Splitter(node) => node.children.clone(),
-            Node::End(terminal) => {
-                terminal.acum_value += 1;
-                return;
-            }
-        };
-
-        for child_id in children {
-            self.propagate(child_id);
-        }
-    }
-
-    pub fn back_propagate(&mut self, node_id: usize) -> usize {
-        let nodes = self.nodes.clone();
-        let node = nodes.get(&node_id).unwrap();
-        match node {
-            Node::End(_) => {}
-            Node::Splitter(splitter_node) => {
-                let acum_value: usize = splitter_node
-                    .children
-                    .iter()
-                    .map(|child_id| {
-                        match self.nodes.get(child_id).unwrap().clone() {
-                            Node::Initial(_) => {
-                                panic!("Initial node cannot be child")
-                            }
-                            Node::Splitter(splitter_node) => {
-                                splitter_node.acum_value
-                            }
-                            Node::End(terminal_node) => {
-                                terminal_node.acum_value
-                            }
-                        }
-                    })
-                    .sum();
-
-                let node = self.nodes.get_mut(&node_id).unwrap();
-                if let Node::Splitter(n) = node {
-                    n.acum_value += acum_value
-                }
-            }
-            Node::Initial(initial_node) => {
-                let child = self.nodes.get(&initial_node.child).unwrap();
-                return if let Node::Splitter(n) = child {
-                    n.acum_value
-                } else {
-                    panic!("child must be a splitter")
-                };
-            }
-        };
-
-        self.back_propagate(node_id - 1)
-    }
-
-    fn get_node_acum_value(&self, node: Node) -> usize {
-        match node {
-            Node::Initial(initial_node) => initial_node.acum_value,
-            Node::Splitter(splitter_node) => splitter_node.acum_value,
-            Node::End(terminal_node) => terminal_node.acum_value,
-        }
-        .clone()
-    }
-
-    pub fn part_2(&mut self) -> usize {
-        self.back_propagate(self.nodes.len() - 1)
-    }
-
-    pub fn part_2_v1(&mut self) -> usize {
-        self.propagate(self.initial_node_id);
-        self.terminal_node_ids
-            .iter()
-            .map(|id| match self.nodes.get(id).expect("id must exist") {
-                Node::Initial(_) | Node::Splitter(_) => {
-                    panic!("must not be terminal node")
-                }
-                Node::End(terminal_node) => terminal_node.acum_value,
-            })
-            .sum()
-    }
-}
-
-#[cfg(test)]
-mod d07 {
-    use super::*;
-
-    #[test]
-    fn test_part1_example() {
-        let manifold = TachyonManifold::new("src/days/inputs/07/example.txt");
-        assert_eq!(21, manifold.part_1());
-    }
-
-    #[test]
-    fn test_part1() {
-        let manifold = TachyonManifold::new("src/days/inputs/07/input.txt");
-        assert_eq!(1570, manifold.part_1());
-    }
-
-    #[test]
-    fn test_part2_example() {
-        let mut manifold = Graph::new("src/days/inputs/07/example.txt");
-        assert_eq!(40, manifold.part_2());
-    }
-
-    #[test]
-    fn test_part2() {
-        let mut manifold = Graph::new("src/days/inputs/07/input.txt");
-        assert_eq!(15118009521693, manifold.part_2());
-    }
-}
@@ -1,24 +0,0 @@
-#[cfg(test)]
-mod <name> {
-    use super::*;
-
-    #[test]
-    fn test_part1_example() {
-        todo!();
-    }
-
-    #[test]
-    fn test_part1() {
-        todo!();
-    }
-
-    #[test]
-    fn test_part2_example() {
-        todo!();
-    }
-
-    #[test]
-    fn test_part2() {
-        todo!();
-    }
-}
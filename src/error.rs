@@ -0,0 +1,194 @@
+use crate::utils::dag::DagError;
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// 1-based location of a problem in a puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    UnexpectedChar {
+        found: char,
+        at: Position,
+    },
+    BadNumber {
+        text: String,
+        at: Position,
+    },
+    MissingSection {
+        section: &'static str,
+        at: Position,
+    },
+    /// A graph built from the input that the puzzle cannot be solved on.
+    Graph(DagError),
+    /// A well-formed input outside what the solution knows how to solve.
+    Unsupported(String),
+    Config(String),
+    Http(String),
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Position { line, column }
+    }
+}
+
+impl Error {
+    pub fn unexpected_char(found: char, line: usize, column: usize) -> Self {
+        Error::UnexpectedChar {
+            found,
+            at: Position::new(line, column),
+        }
+    }
+
+    pub fn bad_number(text: &str, line: usize, column: usize) -> Self {
+        Error::BadNumber {
+            text: text.to_string(),
+            at: Position::new(line, column),
+        }
+    }
+
+    pub fn missing(section: &'static str, line: usize, column: usize) -> Self {
+        Error::MissingSection {
+            section,
+            at: Position::new(line, column),
+        }
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Error::Io(_)
+            | Error::Graph(_)
+            | Error::Unsupported(_)
+            | Error::Config(_)
+            | Error::Http(_) => None,
+            Error::UnexpectedChar { at, .. }
+            | Error::BadNumber { at, .. }
+            | Error::MissingSection { at, .. } => Some(*at),
+        }
+    }
+
+    /// Moves an error found while parsing a single line onto `line` of the
+    /// whole input.
+    pub fn at_line(mut self, line: usize) -> Self {
+        match &mut self {
+            Error::Io(_)
+            | Error::Graph(_)
+            | Error::Unsupported(_)
+            | Error::Config(_)
+            | Error::Http(_) => {}
+            Error::UnexpectedChar { at, .. }
+            | Error::BadNumber { at, .. }
+            | Error::MissingSection { at, .. } => at.line = line,
+        }
+        self
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::UnexpectedChar { found, at } => {
+                write!(f, "{at}: unexpected character {found:?}")
+            }
+            Error::BadNumber { text, at } => {
+                write!(f, "{at}: bad number {text:?}")
+            }
+            Error::MissingSection { section, at } => {
+                write!(f, "{at}: missing {section}")
+            }
+            Error::Graph(e) => write!(f, "{e}"),
+            Error::Unsupported(message)
+            | Error::Config(message)
+            | Error::Http(message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Graph(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<DagError> for Error {
+    fn from(e: DagError) -> Self {
+        Error::Graph(e)
+    }
+}
+
+/// 1-based column of `part`, which must be a subslice of `line`.
+pub fn column(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `part`, a subslice of `line`, reporting a bad number at its
+/// column.
+pub fn parse_number<T: std::str::FromStr>(
+    line: &str,
+    part: &str,
+    line_number: usize,
+) -> Result<T> {
+    part.trim()
+        .parse()
+        .map_err(|_| Error::bad_number(part, line_number, column(line, part)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn column_of_subslice() {
+        let line = "12,345,6";
+        let parts: Vec<&str> = line.split(',').collect();
+        assert_eq!(1, column(line, parts[0]));
+        assert_eq!(4, column(line, parts[1]));
+        assert_eq!(8, column(line, parts[2]));
+    }
+
+    #[test]
+    fn bad_number_points_at_token() {
+        let line = "3,4x,5";
+        let part = line.split(',').nth(1).unwrap();
+        let error = parse_number::<u64>(line, part, 7).unwrap_err();
+        assert_eq!(Some(Position::new(7, 3)), error.position());
+        assert_eq!("line 7, column 3: bad number \"4x\"", error.to_string());
+    }
+
+    #[test]
+    fn at_line_moves_position() {
+        let error = Error::unexpected_char('#', 1, 5).at_line(9);
+        assert_eq!(
+            "line 9, column 5: unexpected character '#'",
+            error.to_string()
+        );
+    }
+}
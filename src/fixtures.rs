@@ -0,0 +1,32 @@
+//! Tests generated by `build.rs` from `inputs/`: every compiled day against
+//! both of its inputs, for both parts, checked against the year's answers.
+
+use crate::input::{
+    InputKind,
+    Locator,
+};
+use crate::manifest::Manifest;
+use crate::years;
+
+// Unused when no day is compiled.
+#[allow(dead_code)]
+fn check(year: u16, day: u8, kind: &str, part: u8) {
+    let locator = Locator::default();
+    let kind = InputKind::from_name(kind).unwrap();
+    let raw = locator.read_answers(year).unwrap();
+    let manifest = Manifest::parse_from(&raw, &locator.answers(year)).unwrap();
+    let expected = manifest.get(day, kind, part).unwrap();
+
+    let input = locator.read(year, day, kind).unwrap();
+    let parsed = years::get(year)
+        .unwrap()
+        .day(day)
+        .unwrap()
+        .parse(&input)
+        .unwrap();
+    for variant in parsed.variants(part) {
+        assert_eq!(*expected, parsed.variant(part, variant), "{variant}");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
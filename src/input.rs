@@ -0,0 +1,232 @@
+use crate::config::Config;
+use crate::error::Result;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::io::{
+    self,
+    BufRead,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Where the inputs live, relative to the crate root. Each year has its own
+/// directory with a subdirectory per day and its answers manifest.
+pub const INPUTS_DIR: &str = "inputs";
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Which of a day's input files to use.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InputKind {
+    Example,
+    Input,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Example, InputKind::Input];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Example => "example",
+            InputKind::Input => "input",
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{}.txt", self.name())
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        InputKind::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Finds every day's input files, and the answers manifest, under one
+/// directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locator {
+    dir: PathBuf,
+}
+
+impl Locator {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Locator { dir: dir.into() }
+    }
+
+    /// Takes the first of `flag`, the configured `input_dir` (which
+    /// `AOC_INPUT_DIR` overrides) and the crate's own inputs.
+    pub fn resolve(flag: Option<&Path>, config: &Config) -> Self {
+        match flag.or(config.input_dir.as_deref()) {
+            Some(dir) => Locator::new(dir),
+            None => Locator::default(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8, kind: InputKind) -> PathBuf {
+        self.dir.join(relative(year, day, kind))
+    }
+
+    pub fn answers(&self, year: u16) -> PathBuf {
+        self.dir.join(answers(year))
+    }
+
+    pub fn read(
+        &self,
+        year: u16,
+        day: u8,
+        kind: InputKind,
+    ) -> io::Result<String> {
+        self.read_file(&relative(year, day, kind))
+    }
+
+    pub fn read_answers(&self, year: u16) -> io::Result<String> {
+        self.read_file(&answers(year))
+    }
+
+    /// Reads `relative` from disk, falling back to the copy embedded at
+    /// build time when the file is missing.
+    fn read_file(&self, relative: &str) -> io::Result<String> {
+        let path = self.dir.join(relative);
+        match fs::read_to_string(&path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                embedded(relative).map(str::to_string).ok_or(e)
+            }
+            read => read,
+        }
+        .map_err(|e| {
+            io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+        })
+    }
+}
+
+impl Default for Locator {
+    fn default() -> Self {
+        Locator::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUTS_DIR))
+    }
+}
+
+fn relative(year: u16, day: u8, kind: InputKind) -> String {
+    format!("{year}/{day:02}/{}", kind.file_name())
+}
+
+fn answers(year: u16) -> String {
+    format!("{year}/{ANSWERS_FILE}")
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    // `FILES`, every file under the inputs directory keyed by its relative
+    // path, as written by `build.rs`.
+    include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+}
+
+#[cfg(feature = "embed-inputs")]
+fn embedded(relative: &str) -> Option<&'static str> {
+    embedded::FILES
+        .iter()
+        .find(|(path, _)| *path == relative)
+        .map(|(_, contents)| *contents)
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded(_relative: &str) -> Option<&'static str> {
+    None
+}
+
+/// Non-blank lines of `reader`, each paired with its 1-based line number.
+pub fn lines<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<(usize, String)>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(Ok((idx + 1, line))),
+            Err(e) => Some(Err(e.into())),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn resolve_input_dir() {
+        let mut config = Config::default();
+        assert_eq!(Locator::default(), Locator::resolve(None, &config));
+        assert!(Locator::default().dir().ends_with("inputs"));
+
+        config.input_dir = Some(PathBuf::from("/from/config"));
+        assert_eq!(
+            Locator::new("/from/config"),
+            Locator::resolve(None, &config)
+        );
+        assert_eq!(
+            Locator::new("/from/flag"),
+            Locator::resolve(Some(Path::new("/from/flag")), &config)
+        );
+    }
+
+    #[test]
+    fn input_paths() {
+        let locator = Locator::new("inputs");
+        assert_eq!(
+            PathBuf::from("inputs/2025/04/input.txt"),
+            locator.path(2025, 4, InputKind::Input)
+        );
+        assert_eq!(
+            PathBuf::from("inputs/2015/12/example.txt"),
+            locator.path(2015, 12, InputKind::Example)
+        );
+        assert_eq!(
+            PathBuf::from("inputs/2025/answers.toml"),
+            locator.answers(2025)
+        );
+    }
+
+    #[test]
+    fn read_from_any_directory() {
+        let example = Locator::default()
+            .read(2025, 4, InputKind::Example)
+            .unwrap();
+        assert!(example.starts_with("..@@.@@@@."));
+
+        let error = Locator::new("no/such/dir")
+            .read(2025, 99, InputKind::Input)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("no/such/dir/2025/99/input.txt: "));
+    }
+
+    #[test]
+    fn numbers_skip_blank_lines() {
+        let lines: Vec<(usize, String)> = lines("a\n\nb\r\nc\n\n".as_bytes())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            vec![
+                (1, "a".to_string()),
+                (3, "b".to_string()),
+                (4, "c".to_string())
+            ],
+            lines
+        );
+    }
+}
@@ -1,2 +1,20 @@
-pub mod days;
+pub mod answer;
+pub mod bench;
+pub mod cancel;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod error;
+#[cfg(test)]
+mod fixtures;
+pub mod input;
+pub mod manifest;
+pub mod memory;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
+pub mod watch;
+pub mod worker;
+pub mod years;
@@ -1,96 +1,142 @@
-use advent_of_code::days::{
-    d01::Document,
-    d02::Products,
-    d03::Banks,
-    d04::Grid,
-    d05::Database,
-    d06::{
-        Worksheet,
-        Worksheet2,
-    },
-    d07::{
-        Graph,
-        TachyonManifold,
-    },
-    d08::Rig,
-    d09,
+use advent_of_code::cli::{
+    Cli,
+    Command,
+    FetchArgs,
+    RunArgs,
+    SubmitArgs,
+    WatchArgs,
 };
+use advent_of_code::client::{
+    self,
+    Client,
+    Fetched,
+    Verdict,
+};
+use advent_of_code::config::Config;
+use advent_of_code::input::{
+    InputKind,
+    Locator,
+};
+use advent_of_code::runner;
+use advent_of_code::scaffold;
+use advent_of_code::watch;
+use advent_of_code::years::{
+    self,
+    Year,
+};
+use clap::Parser;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match Config::load(&Config::path()) {
+        Ok(config) => execute(cli, &config),
+        Err(e) => Err(e.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: Cli, config: &Config) -> Result<(), String> {
+    let locator = Locator::resolve(cli.input_dir.as_deref(), config);
+    let year = match cli.year {
+        Some(year) => {
+            years::get(year).ok_or(format!("year {year} is not registered"))?
+        }
+        None => years::latest(),
+    };
+
+    match cli.command {
+        Some(Command::Run(args)) => runner::run(&locator, year, &args),
+        Some(Command::Verify(args)) => runner::verify(&locator, year, &args),
+        Some(Command::Bench(args)) => runner::bench(&locator, year, &args),
+        Some(Command::NewDay(args)) => new_day(year.year, args.day),
+        Some(Command::Fetch(args)) => fetch(&locator, year, config, &args),
+        Some(Command::Submit(args)) => submit(&locator, year, config, &args),
+        Some(Command::Watch(args)) => watch(&locator, year, &args),
+        None => {
+            println!("Advent of code {}!", year.year);
+            runner::run(&locator, year, &RunArgs::default())
+        }
+    }
+}
+
+fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let created =
+        scaffold::new_day(root, year, day).map_err(|e| e.to_string())?;
+    for path in created {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn watch(
+    locator: &Locator,
+    year: &Year,
+    args: &WatchArgs,
+) -> Result<(), String> {
+    year.day(args.day).ok_or(format!(
+        "day {} of {} is not registered",
+        args.day, year.year
+    ))?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let interval = Duration::from_millis(args.interval);
+    watch::watch(root, locator, year.year, args.day, interval)
+}
+
+/// A client set up from `aoc.toml` and the environment, with `base_url`
+/// taking precedence over both.
+fn connect(config: &Config, base_url: Option<&str>) -> Result<Client, String> {
+    let base_url = base_url
+        .or(config.base_url.as_deref())
+        .unwrap_or(client::DEFAULT_BASE_URL);
+    let session = config.session().map_err(|e| e.to_string())?;
+    Ok(Client::new(base_url, session))
+}
+
+fn fetch(
+    locator: &Locator,
+    year: &Year,
+    config: &Config,
+    args: &FetchArgs,
+) -> Result<(), String> {
+    let path = locator.path(year.year, args.day, InputKind::Input);
+    let fetched = connect(config, args.base_url.as_deref())?
+        .fetch(year.year, args.day, &path)
+        .map_err(|e| e.to_string())?;
+    match fetched {
+        Fetched::Cache => println!("{} is already cached", path.display()),
+        Fetched::Download => println!("wrote {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(
+    locator: &Locator,
+    year: &Year,
+    config: &Config,
+    args: &SubmitArgs,
+) -> Result<(), String> {
+    let client = connect(config, args.base_url.as_deref())?;
+    let answer = runner::solve(locator, year, args.day, args.part, args.force)?;
+    println!("day {} part {}: submitting {answer}", args.day, args.part);
 
-fn main() {
-    println!("Advent of code 2025!");
-    println!("---------------------------");
-    println!(
-        "Day 1\n\t1: {}",
-        Document::new("src/days/inputs/01/p1.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Document::new("src/days/inputs/01/p1.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 2\n\t1: {}",
-        Products::new("src/days/inputs/02/p1.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Products::new("src/days/inputs/02/p1.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 3\n\t1: {}",
-        Banks::new("src/days/inputs/03/input.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Banks::new("src/days/inputs/03/input.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 4\n\t1: {}",
-        Grid::new("src/days/inputs/04/input.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Grid::new("src/days/inputs/04/input.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 5\n\t1: {}",
-        Database::new("src/days/inputs/05/input.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Database::new("src/days/inputs/05/input.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 6\n\t1: {}",
-        Worksheet::new("src/days/inputs/06/input.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Worksheet2::new("src/days/inputs/06/input.txt").solve()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 7\n\t1: {}",
-        TachyonManifold::new("src/days/inputs/07/input.txt").part_1()
-    );
-    println!(
-        "\t2: {}",
-        Graph::new("src/days/inputs/07/input.txt").part_2()
-    );
-    println!("---------------------------");
-    println!(
-        "Day 8\n\t1: {}",
-        Rig::new("src/days/inputs/08/input.txt").part_1(1000)
-    );
-    println!("\t2: {}", Rig::new("src/days/inputs/08/input.txt").part_2());
-    println!("---------------------------");
-    println!(
-        "Day 9\n\t1: {}",
-        d09::part_1("src/days/inputs/09/input.txt")
-    );
-    // println!("\t2: {}", Rig::new("src/days/inputs/08/input.txt").part_2());
+    let verdict = client
+        .submit(year.year, args.day, args.part, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("{verdict}");
+    match verdict {
+        Verdict::Correct | Verdict::AlreadySolved => Ok(()),
+        _ => Err(format!("answer {answer} was not accepted")),
+    }
 }
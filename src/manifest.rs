@@ -0,0 +1,169 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input::InputKind;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use toml::{
+    Table,
+    Value,
+};
+
+/// Expected answers keyed by day, input and part, as stored in
+/// `answers.toml`. A day's `ignore` table gives the reason each listed part
+/// is not expected to pass yet.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    answers: BTreeMap<(u8, InputKind, u8), Answer>,
+    ignored: BTreeMap<(u8, u8), String>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("{}: {e}", path.display())))?;
+        Self::parse_from(&raw, path)
+    }
+
+    /// Parses `raw`, naming `path` in any error.
+    pub fn parse_from(raw: &str, path: &Path) -> Result<Self> {
+        raw.parse().map_err(|e| match e {
+            Error::Config(message) => {
+                Error::Config(format!("{}: {message}", path.display()))
+            }
+            e => e,
+        })
+    }
+
+    pub fn get(&self, day: u8, kind: InputKind, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, kind, part))
+    }
+
+    pub fn ignored(&self, day: u8, part: u8) -> Option<&str> {
+        self.ignored.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(
+        &mut self,
+        day: u8,
+        kind: InputKind,
+        part: u8,
+        answer: Answer,
+    ) {
+        self.answers.insert((day, kind, part), answer);
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let table: Table = input
+            .parse()
+            .map_err(|e: toml::de::Error| Error::Config(e.message().into()))?;
+
+        let mut manifest = Manifest::default();
+        for (day_key, inputs) in &table {
+            let day: u8 = day_key
+                .parse()
+                .map_err(|_| invalid(format!("day {day_key:?}")))?;
+
+            for (kind_key, parts) in as_table(inputs, day_key)? {
+                if kind_key == "ignore" {
+                    for (part_key, reason) in as_table(parts, kind_key)? {
+                        let reason = reason.as_str().ok_or_else(|| {
+                            invalid(format!("ignore reason {reason}"))
+                        })?;
+                        manifest
+                            .ignored
+                            .insert((day, to_part(part_key)?), reason.into());
+                    }
+                    continue;
+                }
+
+                let kind = InputKind::from_name(kind_key)
+                    .ok_or_else(|| invalid(format!("input {kind_key:?}")))?;
+                for (part_key, value) in as_table(parts, kind_key)? {
+                    let part = to_part(part_key)?;
+                    manifest.insert(day, kind, part, to_answer(value)?);
+                }
+            }
+        }
+
+        Ok(manifest)
+    }
+}
+
+fn as_table<'a>(value: &'a Value, key: &str) -> Result<&'a Table> {
+    value
+        .as_table()
+        .ok_or_else(|| invalid(format!("{key:?} must be a table")))
+}
+
+fn to_part(key: &str) -> Result<u8> {
+    match key {
+        "part_1" => Ok(1),
+        "part_2" => Ok(2),
+        _ => Err(invalid(format!("part {key:?}"))),
+    }
+}
+
+fn to_answer(value: &Value) -> Result<Answer> {
+    match value {
+        Value::Integer(n) => Ok((*n).into()),
+        Value::String(s) => Ok(s.parse().unwrap_or_else(|e| match e {})),
+        _ => Err(invalid(format!("answer {value}"))),
+    }
+}
+
+fn invalid(what: String) -> Error {
+    Error::Config(format!("invalid {what} in answers manifest"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let manifest: Manifest = r#"
+            [01.example]
+            part_1 = 3
+            part_2 = "340282366920938463463374607431768211455"
+
+            [12.input]
+            part_2 = "LHBFQ"
+
+            [12.ignore]
+            part_1 = "not solved yet"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(Some(&3.into()), manifest.get(1, InputKind::Example, 1));
+        assert_eq!(
+            Some(&u128::MAX.into()),
+            manifest.get(1, InputKind::Example, 2)
+        );
+        assert_eq!(None, manifest.get(1, InputKind::Input, 1));
+        assert_eq!(
+            Some(&"LHBFQ".into()),
+            manifest.get(12, InputKind::Input, 2)
+        );
+        assert_eq!(Some("not solved yet"), manifest.ignored(12, 1));
+        assert_eq!(None, manifest.ignored(12, 2));
+    }
+
+    #[test]
+    fn reject_unknown_part() {
+        let error = "[01.input]\npart_3 = 1".parse::<Manifest>().unwrap_err();
+        assert_eq!(
+            "invalid part \"part_3\" in answers manifest",
+            error.to_string()
+        );
+    }
+}
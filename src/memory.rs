@@ -0,0 +1,146 @@
+//! Heap accounting for `bench`. With the `count-allocs` feature `Counting`
+//! becomes the global allocator, and `measure` reports what a piece of work
+//! allocated. The counters are shared by every thread, so measure one thing
+//! at a time.
+
+use serde::Serialize;
+use std::alloc::{
+    GlobalAlloc,
+    Layout,
+    System,
+};
+use std::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+/// Whether allocations are being counted at all.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+/// What one piece of work allocated. Peak live bytes are counted from what
+/// was live when it started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Usage {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    pub peak_bytes: u64,
+}
+
+/// The system allocator, counting what goes through it.
+pub struct Counting;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+fn grow(old: usize, new: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(new as u64, Ordering::Relaxed);
+    let added = new.saturating_sub(old) as u64;
+    let freed = old.saturating_sub(new) as u64;
+    let live = LIVE.fetch_add(added, Ordering::Relaxed) + added;
+    LIVE.fetch_sub(freed, Ordering::Relaxed);
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            grow(0, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            grow(0, layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            grow(layout.size(), new_size);
+        }
+        new
+    }
+}
+
+/// Runs `f` and reports what it allocated, result included, or none when
+/// allocations are not counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, Some(usage))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Other tests allocate at the same time, so counts are only bounded below.
+    #[test]
+    fn count_through_allocator() {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        unsafe {
+            let ptr = Counting.alloc(layout);
+            let ptr = Counting.realloc(ptr, layout, 3000);
+            Counting.dealloc(ptr, Layout::from_size_align(3000, 8).unwrap());
+        }
+
+        assert!(ALLOCATIONS.load(Ordering::Relaxed) - allocations >= 2);
+        assert!(ALLOCATED.load(Ordering::Relaxed) - allocated >= 4000);
+        assert!(PEAK.load(Ordering::Relaxed) >= 3000);
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocs")]
+    fn measure_allocations() {
+        let (v, usage) = measure(|| vec![0u8; 4096]);
+        let usage = usage.unwrap();
+        assert_eq!(4096, v.len());
+        assert!(usage.allocations >= 1);
+        assert!(usage.allocated_bytes >= 4096);
+        assert!(usage.peak_bytes >= 4096);
+    }
+
+    #[test]
+    #[cfg(not(feature = "count-allocs"))]
+    fn nothing_measured_when_off() {
+        assert_eq!((3, None), measure(|| 1 + 2));
+    }
+}
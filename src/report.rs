@@ -0,0 +1,153 @@
+//! Structured output of `run`, `verify` and `bench`. All three write rows of
+//! the same `Record`, leaving out what does not apply, so scripts can read
+//! any of them the same way.
+
+use crate::bench::Stats;
+use crate::cli::Format;
+use crate::memory::Usage;
+use crate::runner::Status;
+use serde::Serialize;
+use std::io::{
+    self,
+    Write,
+};
+
+/// One part, or the parsing, of one day against one input. Field order is
+/// the CSV column order.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// None for parsing.
+    pub part: Option<u8>,
+    pub variant: Option<&'static str>,
+    /// `example`, `input`, or the file given to `run --input`.
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Option<Status>,
+    pub error: Option<String>,
+    pub iterations: Option<usize>,
+    pub min_ns: Option<u128>,
+    pub median_ns: Option<u128>,
+    pub p95_ns: Option<u128>,
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+}
+
+impl Record {
+    pub fn with_stats(self, stats: Stats) -> Self {
+        Record {
+            iterations: Some(stats.iterations),
+            min_ns: Some(stats.min_ns),
+            median_ns: Some(stats.median_ns),
+            p95_ns: Some(stats.p95_ns),
+            ..self
+        }
+    }
+
+    pub fn with_memory(self, usage: Option<Usage>) -> Self {
+        Record {
+            allocations: usage.map(|u| u.allocations),
+            allocated_bytes: usage.map(|u| u.allocated_bytes),
+            peak_bytes: usage.map(|u| u.peak_bytes),
+            ..self
+        }
+    }
+}
+
+/// Writes `records` as a JSON array or as CSV with a header row. Text is
+/// left to each command.
+pub fn write(
+    format: Format,
+    records: &[Record],
+    mut out: impl Write,
+) -> io::Result<()> {
+    match format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, records)?;
+            writeln!(out)
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let stats = Stats {
+            iterations: 1,
+            min_ns: 1500,
+            median_ns: 1500,
+            p95_ns: 1500,
+        };
+        vec![
+            Record {
+                year: 2025,
+                day: 7,
+                part: Some(2),
+                variant: Some("propagate"),
+                input: "example".into(),
+                answer: Some("40".into()),
+                expected: Some("40".into()),
+                status: Some(Status::Pass),
+                ..Record::default()
+            },
+            Record {
+                year: 2025,
+                day: 7,
+                input: "input".into(),
+                ..Record::default()
+            }
+            .with_stats(stats)
+            .with_memory(Some(Usage {
+                allocations: 3,
+                allocated_bytes: 96,
+                peak_bytes: 64,
+            })),
+        ]
+    }
+
+    fn written(format: Format) -> String {
+        let mut out = Vec::new();
+        write(format, &records(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn csv_columns() {
+        assert_eq!(
+            "year,day,part,variant,input,answer,expected,status,error,\
+             iterations,min_ns,median_ns,p95_ns,allocations,allocated_bytes,\
+             peak_bytes\n\
+             2025,7,2,propagate,example,40,40,pass,,,,,,,,\n\
+             2025,7,,,input,,,,,1,1500,1500,1500,3,96,64\n",
+            written(Format::Csv)
+        );
+    }
+
+    #[test]
+    fn json_nulls() {
+        let json: serde_json::Value =
+            serde_json::from_str(&written(Format::Json)).unwrap();
+        assert_eq!("pass", json[0]["status"]);
+        assert_eq!(serde_json::Value::Null, json[0]["min_ns"]);
+        assert_eq!(serde_json::Value::Null, json[1]["part"]);
+        assert_eq!(1500, json[1]["p95_ns"]);
+    }
+
+    #[test]
+    fn text_is_left_to_commands() {
+        assert_eq!("", written(Format::Text));
+    }
+}
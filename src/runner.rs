@@ -0,0 +1,1072 @@
+use crate::answer::Answer;
+use crate::bench::{
+    self,
+    Stage,
+    Stats,
+};
+use crate::cli::{
+    BenchArgs,
+    Format,
+    RunArgs,
+    VerifyArgs,
+};
+use crate::error::Error;
+use crate::input::{
+    InputKind,
+    Locator,
+};
+use crate::manifest::Manifest;
+use crate::memory::{
+    self,
+    Usage,
+};
+use crate::report::{
+    self,
+    Record,
+};
+use crate::solution::{
+    Day,
+    Parsed,
+    MAIN,
+};
+use crate::worker::{
+    self,
+    Failure,
+};
+use crate::years::Year;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io::{
+    self,
+    Read,
+};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Ignored,
+    /// Another variant than `MAIN` gave a different answer than it.
+    Disagree,
+    TimedOut,
+}
+
+/// One part run against one input, next to the answer it should give.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub kind: InputKind,
+    pub variant: &'static str,
+    pub expected: Option<Answer>,
+    pub actual: std::result::Result<Answer, Failure>,
+    /// Why the manifest does not expect this part to pass yet.
+    pub ignored: Option<String>,
+    /// What `MAIN` answered, when this is another variant.
+    pub main: Option<Answer>,
+}
+
+impl Check {
+    /// A part that gives its expected answer passes even when ignored, so
+    /// a stale `ignore` entry shows up.
+    pub fn status(&self) -> Status {
+        if let (Some(main), Ok(actual)) = (&self.main, &self.actual)
+            && main != actual
+        {
+            return Status::Disagree;
+        }
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+            _ if self.ignored.is_some() => Status::Ignored,
+            (_, Err(Failure::TimedOut(_))) => Status::TimedOut,
+            (None, _) => Status::Missing,
+            (Some(_), _) => Status::Fail,
+        }
+    }
+}
+
+impl Check {
+    pub fn record(&self, year: u16) -> Record {
+        let (answer, error) = match &self.actual {
+            Ok(answer) => (Some(answer.to_string()), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Record {
+            year,
+            day: self.day,
+            part: Some(self.part),
+            variant: Some(self.variant),
+            input: self.kind.to_string(),
+            answer,
+            expected: self.expected.as_ref().map(Answer::to_string),
+            status: Some(self.status()),
+            error,
+            ..Record::default()
+        }
+    }
+}
+
+impl Timing {
+    pub fn record(&self, year: u16) -> Record {
+        let record = Record {
+            year,
+            day: self.day,
+            part: match self.stage {
+                Stage::Parse => None,
+                Stage::Part1 => Some(1),
+                Stage::Part2 => Some(2),
+            },
+            variant: self.variant,
+            input: self.input.to_string(),
+            ..Record::default()
+        };
+        match &self.stats {
+            Ok(stats) => record.with_stats(*stats).with_memory(self.memory),
+            Err(e) => Record {
+                error: Some(e.clone()),
+                ..record
+            },
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail => f.pad("FAIL"),
+            Status::Missing => f.pad("missing"),
+            Status::Ignored => f.pad("ignored"),
+            Status::Disagree => f.pad("DISAGREE"),
+            Status::TimedOut => f.pad("timed out"),
+        }
+    }
+}
+
+/// Timings of one stage of a day against one input.
+#[derive(Debug)]
+pub struct Timing {
+    pub day: u8,
+    pub input: InputKind,
+    pub stage: Stage,
+    /// Which implementation of a part was timed, none for parsing.
+    pub variant: Option<&'static str>,
+    /// Why the stage could not be timed if it failed.
+    pub stats: Result<Stats, String>,
+    /// What one more, untimed run allocated, with `count-allocs`.
+    pub memory: Option<Usage>,
+}
+
+/// Reads a puzzle input, treating `-` as stdin.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+        })
+    }
+}
+
+/// The compiled days of `year` to work on: just `day`, or all of them.
+fn select(year: &Year, day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    let Some(day) = day else {
+        let (compiled, missing): (Vec<_>, Vec<_>) = year
+            .days
+            .iter()
+            .partition(|d| d.missing_feature().is_none());
+        for day in missing {
+            eprintln!(
+                "day {} of {} is not compiled, skipping",
+                day.day, year.year
+            );
+        }
+        return Ok(compiled);
+    };
+
+    let found = year
+        .day(day)
+        .ok_or(format!("day {day} of {} is not registered", year.year))?;
+    match found.missing_feature() {
+        Some(feature) => Err(format!(
+            "day {day} of {} is not compiled, enable the `{feature}` feature",
+            year.year
+        )),
+        None => Ok(vec![found]),
+    }
+}
+
+fn input_name(path: &Path) -> String {
+    if path == Path::new("-") {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+pub fn run(
+    locator: &Locator,
+    year: &Year,
+    args: &RunArgs,
+) -> Result<(), String> {
+    let selected = select(year, args.day.filter(|_| !args.all))?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let kind = if args.example {
+        InputKind::Example
+    } else {
+        InputKind::Input
+    };
+
+    let input_name = |day: &Day| match &args.input {
+        Some(path) => input_name(path),
+        None => locator.path(year.year, day.day, kind).display().to_string(),
+    };
+    let timeout = args.timeout;
+    let solved = worker::map(&selected, args.sequential, |day| {
+        let input = match &args.input {
+            Some(path) => read_input(path),
+            None => locator.read(year.year, day.day, kind),
+        };
+        let parsed = input.map_err(|e| e.to_string()).and_then(|input| {
+            worker::isolate(|| day.parse(&input))?
+                .map(Arc::new)
+                .map_err(|e| format!("{}: {e}", input_name(day)))
+        });
+
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match &parsed {
+                    Ok(parsed) => solve_variant(parsed, part, MAIN, timeout),
+                    Err(e) => Err(Failure::Error(e.clone())),
+                };
+                (part, answer, start.elapsed())
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut records = Vec::new();
+    for (day, parts) in selected.iter().zip(solved) {
+        if args.format == Format::Text {
+            println!("---------------------------");
+            println!("Day {}", day.day);
+        }
+        for (part, answer, elapsed) in parts {
+            let record = Record {
+                year: year.year,
+                day: day.day,
+                part: Some(part),
+                variant: Some(MAIN),
+                input: match &args.input {
+                    Some(_) => input_name(day),
+                    None => kind.to_string(),
+                },
+                ..Record::default()
+            };
+            records.push(match answer {
+                Ok(answer) => {
+                    if args.format == Format::Text {
+                        println!("\t{part}: {answer}");
+                    }
+                    Record {
+                        answer: Some(answer.to_string()),
+                        ..record
+                    }
+                    .with_stats(Stats::from_samples(vec![elapsed]))
+                }
+                Err(failure) => {
+                    if args.format == Format::Text {
+                        match &failure {
+                            Failure::Error(e) => {
+                                println!("\t{part}: error: {e}")
+                            }
+                            Failure::TimedOut(_) => {
+                                println!("\t{part}: {failure}")
+                            }
+                        }
+                    }
+                    Record {
+                        status: matches!(failure, Failure::TimedOut(_))
+                            .then_some(Status::TimedOut),
+                        error: Some(failure.to_string()),
+                        ..record
+                    }
+                }
+            });
+        }
+    }
+
+    report::write(args.format, &records, io::stdout())
+        .map_err(|e| e.to_string())?;
+
+    let failed = records.iter().filter(|r| r.error.is_some()).count();
+    if failed > 0 {
+        Err(format!("{failed} of {} parts failed", records.len()))
+    } else {
+        Ok(())
+    }
+}
+
+/// What the scaffold's template answers for a part not written yet.
+pub const UNSOLVED: &str = "unsolved";
+
+/// Solves `part` of `day` against its real input for `submit`. Unless
+/// `force`d, refuses a part that answers.toml ignores or that still gives the
+/// template's answer, since submitting those only costs a wrong answer. A
+/// panicking part is an error.
+pub fn solve(
+    locator: &Locator,
+    year: &Year,
+    day: u8,
+    part: u8,
+    force: bool,
+) -> Result<Answer, String> {
+    let day = select(year, Some(day))?[0];
+    let manifest = answers_if_any(locator, year)?;
+    if let Some(reason) = manifest.ignored(day.day, part)
+        && !force
+    {
+        return Err(format!(
+            "day {} part {part} is ignored in answers.toml ({reason}), \
+             use --force to submit it anyway",
+            day.day
+        ));
+    }
+
+    let input = locator
+        .read(year.year, day.day, InputKind::Input)
+        .map_err(|e| e.to_string())?;
+    let answer = worker::isolate(|| day.solve(part, &input))?.map_err(|e| {
+        format!(
+            "{}: {e}",
+            locator.path(year.year, day.day, InputKind::Input).display()
+        )
+    })?;
+    if answer == UNSOLVED && !force {
+        return Err(format!(
+            "day {} part {part} answers \"{answer}\", use --force to submit it \
+             anyway",
+            day.day
+        ));
+    }
+    Ok(answer)
+}
+
+/// The answers.toml of `year`, or an empty one if there is none.
+fn answers_if_any(locator: &Locator, year: &Year) -> Result<Manifest, String> {
+    match locator.read_answers(year.year) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Ok(Manifest::default())
+        }
+        raw => raw
+            .map_err(Error::from)
+            .and_then(|raw| {
+                Manifest::parse_from(&raw, &locator.answers(year.year))
+            })
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// Solves one variant of a part on its own thread, giving up after
+/// `timeout`.
+fn solve_variant(
+    parsed: &Arc<Parsed>,
+    part: u8,
+    variant: &'static str,
+    timeout: Option<Duration>,
+) -> Result<Answer, Failure> {
+    let parsed = Arc::clone(parsed);
+    worker::with_timeout(timeout, move || parsed.variant(part, variant))
+}
+
+/// Runs every variant of both parts of `day` against one of its inputs. A
+/// panic while parsing or solving fails the parts it affects, and a variant
+/// still running after `timeout` is marked as timed out.
+pub fn check(
+    locator: &Locator,
+    year: &Year,
+    day: &Day,
+    kind: InputKind,
+    manifest: &Manifest,
+    timeout: Option<Duration>,
+) -> Vec<Check> {
+    let path = locator.path(year.year, day.day, kind);
+    let parsed = locator
+        .read(year.year, day.day, kind)
+        .map_err(|e| e.to_string())
+        .and_then(|input| {
+            worker::isolate(|| day.parse(&input))?
+                .map(Arc::new)
+                .map_err(|e| format!("{}: {e}", path.display()))
+        });
+
+    let mut checks = Vec::new();
+    for part in [1, 2] {
+        let variants = match &parsed {
+            Ok(parsed) => parsed.variants(part),
+            Err(_) => vec![MAIN],
+        };
+        let mut main = None;
+        for variant in variants {
+            let actual = match &parsed {
+                Ok(parsed) => solve_variant(parsed, part, variant, timeout),
+                Err(e) => Err(Failure::Error(e.clone())),
+            };
+            checks.push(Check {
+                day: day.day,
+                part,
+                kind,
+                variant,
+                expected: manifest.get(day.day, kind, part).cloned(),
+                main: main.clone(),
+                ignored: manifest.ignored(day.day, part).map(String::from),
+                actual,
+            });
+            if variant == MAIN {
+                main = checks.last().unwrap().actual.clone().ok();
+            }
+        }
+    }
+    checks
+}
+
+pub fn verify(
+    locator: &Locator,
+    year: &Year,
+    args: &VerifyArgs,
+) -> Result<(), String> {
+    let manifest = match &args.answers {
+        Some(path) => Manifest::load(path),
+        None => locator
+            .read_answers(year.year)
+            .map_err(Error::from)
+            .and_then(|raw| {
+                Manifest::parse_from(&raw, &locator.answers(year.year))
+            }),
+    }
+    .map_err(|e| e.to_string())?;
+
+    let selected = select(year, args.day)?;
+
+    if args.format == Format::Text {
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:<7}  {:<9}  {:<20}  answer",
+            "day", "part", "variant", "input", "status", "expected"
+        );
+    }
+    let checked = worker::map(&selected, args.sequential, |day| {
+        InputKind::ALL
+            .into_iter()
+            .flat_map(|kind| {
+                check(locator, year, day, kind, &manifest, args.timeout)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut checks = Vec::new();
+    for day in checked {
+        for check in day {
+            if args.format == Format::Text {
+                println!(
+                    "{:>3}  {:>4}  {:<10}  {:<7}  {:<9}  {:<20}  {}",
+                    check.day,
+                    check.part,
+                    check.variant,
+                    check.kind,
+                    check.status(),
+                    match &check.expected {
+                        Some(answer) => answer.to_string(),
+                        None => "-".to_string(),
+                    },
+                    match &check.actual {
+                        Ok(answer) => answer.to_string(),
+                        Err(e) => e.to_string(),
+                    },
+                );
+            }
+            checks.push(check);
+        }
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status() == status).count();
+    let failed =
+        count(Status::Fail) + count(Status::Disagree) + count(Status::TimedOut);
+    if args.format == Format::Text {
+        println!(
+            "\n{} passed, {} failed, {} missing, {} ignored, {} disagreeing, \
+             {} timed out",
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Missing),
+            count(Status::Ignored),
+            count(Status::Disagree),
+            count(Status::TimedOut)
+        );
+    } else {
+        let records: Vec<Record> =
+            checks.iter().map(|check| check.record(year.year)).collect();
+        report::write(args.format, &records, io::stdout())
+            .map_err(|e| e.to_string())?;
+    }
+
+    if failed > 0 {
+        Err(format!("{failed} of {} checks failed", checks.len()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Times parsing and every variant of both parts of `day`, each on its own,
+/// leaving out the parts `manifest` ignores. A stage that fails or panics is
+/// given with its error instead of timings, and a failed parse ends the day
+/// there.
+pub fn time_day(
+    locator: &Locator,
+    year: &Year,
+    day: &Day,
+    kind: InputKind,
+    manifest: &Manifest,
+    warmup: usize,
+    iterations: usize,
+) -> Vec<Timing> {
+    let timing =
+        |stage, variant, stats: Result<(Stats, Option<Usage>), _>| Timing {
+            day: day.day,
+            input: kind,
+            stage,
+            variant,
+            memory: stats.as_ref().ok().and_then(|(_, memory)| *memory),
+            stats: stats.map(|(stats, _)| stats),
+        };
+    let measure = |f: &dyn Fn()| {
+        worker::isolate(|| {
+            (bench::measure(warmup, iterations, f), memory::measure(f).1)
+        })
+    };
+
+    let path = locator.path(year.year, day.day, kind);
+    let parsed = locator
+        .read(year.year, day.day, kind)
+        .map_err(|e| e.to_string())
+        .and_then(|input| {
+            let parsed = worker::isolate(|| day.parse(&input))?
+                .map_err(|e| format!("{}: {e}", path.display()))?;
+            Ok((input, parsed))
+        });
+    let (input, parsed) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => return vec![timing(Stage::Parse, None, Err(e))],
+    };
+
+    let mut timings = vec![timing(
+        Stage::Parse,
+        None,
+        measure(&|| {
+            black_box(day.parse(&input).ok());
+        }),
+    )];
+    for part in [1, 2] {
+        if let Some(reason) = manifest.ignored(day.day, part) {
+            eprintln!(
+                "day {} part {part} is ignored in answers.toml ({reason}), \
+                 skipping",
+                day.day
+            );
+            continue;
+        }
+        for variant in parsed.variants(part) {
+            let stats = measure(&|| {
+                black_box(parsed.variant(part, variant));
+            });
+            timings.push(timing(Stage::part(part), Some(variant), stats));
+        }
+    }
+    timings
+}
+
+pub fn bench(
+    locator: &Locator,
+    year: &Year,
+    args: &BenchArgs,
+) -> Result<(), String> {
+    let selected = select(year, args.day)?;
+    let manifest = answers_if_any(locator, year)?;
+    let kind = if args.example {
+        InputKind::Example
+    } else {
+        InputKind::Input
+    };
+
+    if args.format == Format::Text {
+        print!(
+            "{:>3}  {:<7}  {:<6}  {:<10}  {:>12}  {:>12}  {:>12}",
+            "day", "input", "stage", "variant", "min", "median", "p95"
+        );
+        if memory::ENABLED {
+            print!("  {:>10}  {:>12}  {:>12}", "allocs", "bytes", "peak");
+        }
+        println!();
+    }
+
+    let mut timings = Vec::new();
+    for day in selected {
+        for timing in time_day(
+            locator,
+            year,
+            day,
+            kind,
+            &manifest,
+            args.warmup,
+            args.iterations,
+        ) {
+            if args.format == Format::Text {
+                print!(
+                    "{:>3}  {:<7}  {:<6}  {:<10}",
+                    timing.day,
+                    timing.input,
+                    timing.stage,
+                    timing.variant.unwrap_or("-"),
+                );
+                match &timing.stats {
+                    Ok(stats) => print!(
+                        "  {:>12}  {:>12}  {:>12}",
+                        format!("{:.2?}", stats.min()),
+                        format!("{:.2?}", stats.median()),
+                        format!("{:.2?}", stats.p95()),
+                    ),
+                    Err(e) => print!("  error: {e}"),
+                }
+                if let Some(usage) = timing.memory {
+                    print!(
+                        "  {:>10}  {:>12}  {:>12}",
+                        usage.allocations,
+                        usage.allocated_bytes,
+                        usage.peak_bytes
+                    );
+                }
+                println!();
+            }
+            timings.push(timing);
+        }
+    }
+
+    let records: Vec<Record> =
+        timings.iter().map(|t| t.record(year.year)).collect();
+    report::write(args.format, &records, io::stdout())
+        .map_err(|e| e.to_string())?;
+
+    let failed = records.iter().filter(|r| r.error.is_some()).count();
+    if failed > 0 {
+        Err(format!("{failed} of {} stages failed", records.len()))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn skip_days_not_compiled() {
+        const DAYS: &[Day] = &[Day::not_compiled(6, "y2025-d06")];
+        let year = Year {
+            year: 2025,
+            days: DAYS,
+        };
+        assert!(select(&year, None).unwrap().is_empty());
+        let error = |day| select(&year, Some(day)).err().unwrap();
+        assert_eq!(
+            "day 6 of 2025 is not compiled, enable the `y2025-d06` feature",
+            error(6)
+        );
+        assert_eq!("day 7 of 2025 is not registered", error(7));
+    }
+
+    #[test]
+    #[cfg(feature = "y2025-d04")]
+    fn time_every_stage() {
+        let year = crate::years::get(2025).unwrap();
+        let day = year.day(4).unwrap();
+        let timings = time_day(
+            &Locator::default(),
+            year,
+            day,
+            InputKind::Example,
+            &Manifest::default(),
+            0,
+            2,
+        );
+        let stages: Vec<Stage> = timings.iter().map(|t| t.stage).collect();
+        assert_eq!(vec![Stage::Parse, Stage::Part1, Stage::Part2], stages);
+        assert!(timings.iter().all(|t| {
+            t.stats.as_ref().is_ok_and(|stats| stats.iterations == 2)
+        }));
+    }
+
+    #[test]
+    #[cfg(feature = "y2025-d04")]
+    fn check_statuses() {
+        let mut manifest = Manifest::default();
+        manifest.insert(4, InputKind::Example, 1, 13.into());
+        manifest.insert(4, InputKind::Example, 2, 42.into());
+
+        let locator = Locator::default();
+        let year = crate::years::get(2025).unwrap();
+        let day = year.day(4).unwrap();
+        let checks =
+            check(&locator, year, day, InputKind::Example, &manifest, None);
+        let statuses: Vec<Status> = checks.iter().map(Check::status).collect();
+        assert_eq!(vec![Status::Pass, Status::Fail], statuses);
+
+        let checks =
+            check(&locator, year, day, InputKind::Input, &manifest, None);
+        assert!(checks.iter().all(|c| c.status() == Status::Missing));
+    }
+
+    #[test]
+    #[cfg(feature = "y2025-d07")]
+    fn check_every_variant() {
+        let mut manifest = Manifest::default();
+        manifest.insert(7, InputKind::Example, 2, 40.into());
+
+        let year = crate::years::get(2025).unwrap();
+        let day = year.day(7).unwrap();
+        let checks = check(
+            &Locator::default(),
+            year,
+            day,
+            InputKind::Example,
+            &manifest,
+            None,
+        );
+        let variants: Vec<_> = checks
+            .iter()
+            .filter(|c| c.part == 2)
+            .map(|c| (c.variant, c.status()))
+            .collect();
+        assert_eq!(
+            vec![(MAIN, Status::Pass), ("propagate", Status::Pass)],
+            variants
+        );
+    }
+
+    /// Panics parsing input starting with `!`, and always solving part 2.
+    struct Unfinished;
+
+    impl std::str::FromStr for Unfinished {
+        type Err = Error;
+
+        fn from_str(input: &str) -> crate::error::Result<Self> {
+            assert!(!input.starts_with('!'), "bad input");
+            Ok(Unfinished)
+        }
+    }
+
+    impl crate::solution::Solution for Unfinished {
+        fn part_1(&self) -> Answer {
+            1.into()
+        }
+
+        fn part_2(&self) -> Answer {
+            panic!("not solved")
+        }
+    }
+
+    /// Solves part 2 only once cancelled.
+    struct Endless;
+
+    impl std::str::FromStr for Endless {
+        type Err = Error;
+
+        fn from_str(_: &str) -> crate::error::Result<Self> {
+            Ok(Endless)
+        }
+    }
+
+    impl crate::solution::Solution for Endless {
+        fn part_1(&self) -> Answer {
+            1.into()
+        }
+
+        fn part_2(&self) -> Answer {
+            loop {
+                crate::cancel::checkpoint();
+                std::thread::yield_now();
+            }
+        }
+    }
+
+    #[test]
+    fn slow_part_times_out() {
+        let dir = std::env::temp_dir()
+            .join(format!("advent_of_code_timeout_{}", std::process::id()));
+        let locator = Locator::new(&dir);
+        let path = locator.path(2025, 1, InputKind::Example);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+
+        const DAYS: &[Day] = &[Day::new::<Endless>(1)];
+        let year = Year {
+            year: 2025,
+            days: DAYS,
+        };
+        let timeout = Duration::from_millis(50);
+        let checks = check(
+            &locator,
+            &year,
+            &DAYS[0],
+            InputKind::Example,
+            &Manifest::default(),
+            Some(timeout),
+        );
+        assert_eq!(Ok(1.into()), checks[0].actual);
+        assert_eq!(Err(Failure::TimedOut(timeout)), checks[1].actual);
+        assert_eq!(Status::TimedOut, checks[1].status());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn panics_fail_only_their_part() {
+        let dir = std::env::temp_dir()
+            .join(format!("advent_of_code_panics_{}", std::process::id()));
+        let locator = Locator::new(&dir);
+        for (day, input) in [(1, "ok"), (2, "!")] {
+            let path = locator.path(2025, day, InputKind::Example);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, input).unwrap();
+        }
+
+        const DAYS: &[Day] =
+            &[Day::new::<Unfinished>(1), Day::new::<Unfinished>(2)];
+        let year = Year {
+            year: 2025,
+            days: DAYS,
+        };
+        let checked = worker::map(DAYS, false, |day| {
+            check(
+                &locator,
+                &year,
+                day,
+                InputKind::Example,
+                &Manifest::default(),
+                None,
+            )
+        });
+        let failure = |e: &str| Failure::Error(e.to_string());
+        let actual: Vec<Vec<_>> = checked
+            .into_iter()
+            .map(|checks| checks.into_iter().map(|c| c.actual).collect())
+            .collect();
+        assert_eq!(
+            vec![
+                vec![Ok(1.into()), Err(failure("panicked: not solved"))],
+                vec![
+                    Err(failure("panicked: bad input")),
+                    Err(failure("panicked: bad input"))
+                ],
+            ],
+            actual
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn bench_reports_panics() {
+        let dir = std::env::temp_dir()
+            .join(format!("advent_of_code_bench_{}", std::process::id()));
+        let locator = Locator::new(&dir);
+        for (day, input) in [(1, "ok"), (2, "!")] {
+            let path = locator.path(2025, day, InputKind::Example);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, input).unwrap();
+        }
+
+        const DAYS: &[Day] =
+            &[Day::new::<Unfinished>(1), Day::new::<Unfinished>(2)];
+        let year = Year {
+            year: 2025,
+            days: DAYS,
+        };
+        let time = |day, manifest: &Manifest| {
+            time_day(&locator, &year, day, InputKind::Example, manifest, 0, 1)
+                .into_iter()
+                .map(|t| (t.stage, t.stats.err()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                (Stage::Parse, None),
+                (Stage::Part1, None),
+                (Stage::Part2, Some("panicked: not solved".to_string())),
+            ],
+            time(&DAYS[0], &Manifest::default())
+        );
+        assert_eq!(
+            vec![(Stage::Parse, Some("panicked: bad input".to_string()))],
+            time(&DAYS[1], &Manifest::default())
+        );
+
+        let manifest = Manifest::parse_from(
+            "[01.ignore]\npart_2 = \"not solved yet\"\n",
+            Path::new("answers.toml"),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![(Stage::Parse, None), (Stage::Part1, None)],
+            time(&DAYS[0], &manifest)
+        );
+
+        let failed = time_day(
+            &locator,
+            &year,
+            &DAYS[0],
+            InputKind::Example,
+            &Manifest::default(),
+            0,
+            1,
+        )
+        .pop()
+        .unwrap()
+        .record(2025);
+        assert_eq!(Some("panicked: not solved".into()), failed.error);
+        assert_eq!(None, failed.iterations);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// What a freshly scaffolded day answers.
+    struct Scaffolded;
+
+    impl std::str::FromStr for Scaffolded {
+        type Err = Error;
+
+        fn from_str(_: &str) -> crate::error::Result<Self> {
+            Ok(Scaffolded)
+        }
+    }
+
+    impl crate::solution::Solution for Scaffolded {
+        fn part_1(&self) -> Answer {
+            UNSOLVED.into()
+        }
+
+        fn part_2(&self) -> Answer {
+            UNSOLVED.into()
+        }
+    }
+
+    #[test]
+    fn submit_refuses_unsolved_parts() {
+        let dir = std::env::temp_dir()
+            .join(format!("advent_of_code_submit_{}", std::process::id()));
+        let locator = Locator::new(&dir);
+        for day in 1..=3 {
+            let path = locator.path(2025, day, InputKind::Input);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "ok").unwrap();
+        }
+        fs::write(
+            locator.answers(2025),
+            "[01.ignore]\npart_1 = \"not solved yet\"\n",
+        )
+        .unwrap();
+
+        const DAYS: &[Day] = &[
+            Day::new::<Unfinished>(1),
+            Day::new::<Unfinished>(2),
+            Day::new::<Scaffolded>(3),
+        ];
+        let year = Year {
+            year: 2025,
+            days: DAYS,
+        };
+        let solve = |day, part, force| solve(&locator, &year, day, part, force);
+        assert_eq!(
+            Err("day 1 part 1 is ignored in answers.toml (not solved yet), use \
+                 --force to submit it anyway"
+                .to_string()),
+            solve(1, 1, false)
+        );
+        assert_eq!(Ok(1.into()), solve(1, 1, true));
+        assert_eq!(Ok(1.into()), solve(2, 1, false));
+        assert_eq!(Err("panicked: not solved".to_string()), solve(2, 2, true));
+        assert_eq!(
+            Err(
+                "day 3 part 2 answers \"unsolved\", use --force to submit it \
+                 anyway"
+                    .to_string()
+            ),
+            solve(3, 2, false)
+        );
+        assert_eq!(Ok(UNSOLVED.into()), solve(3, 2, true));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn ignored_unless_passing() {
+        let status = |expected: Option<i32>, actual: i32| {
+            Check {
+                day: 9,
+                part: 2,
+                kind: InputKind::Example,
+                variant: MAIN,
+                expected: expected.map(Answer::from),
+                actual: Ok(actual.into()),
+                ignored: Some("not solved yet".into()),
+                main: None,
+            }
+            .status()
+        };
+        assert_eq!(Status::Ignored, status(Some(24), 50));
+        assert_eq!(Status::Ignored, status(None, 50));
+        assert_eq!(Status::Pass, status(Some(24), 24));
+    }
+
+    #[test]
+    fn missing_input_names_the_file() {
+        let error = read_input(Path::new("no/such/input.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("no/such/input.txt: "));
+    }
+
+    #[test]
+    fn variants_disagreeing_with_main() {
+        let status = |expected: Option<i32>, main: i32, actual: i32| {
+            Check {
+                day: 7,
+                part: 2,
+                kind: InputKind::Example,
+                variant: "propagate",
+                expected: expected.map(Answer::from),
+                actual: Ok(actual.into()),
+                ignored: None,
+                main: Some(main.into()),
+            }
+            .status()
+        };
+        assert_eq!(Status::Pass, status(Some(40), 40, 40));
+        assert_eq!(Status::Missing, status(None, 40, 40));
+        assert_eq!(Status::Disagree, status(None, 40, 55));
+        assert_eq!(Status::Disagree, status(Some(55), 40, 55));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input::{
+    InputKind,
+    INPUTS_DIR,
+};
+use std::fs;
+use std::ops::Range;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Where each year's module tree lives, relative to the crate root.
+pub const YEARS_DIR: &str = "src/years";
+
+const TEMPLATE: &str = include_str!("years/template.rs");
+
+/// The template with its placeholders filled in for `day` of `year`.
+pub fn render(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("<name>", &format!("d{day:02}"))
+        .replace("<year>", &year.to_string())
+        .replace("<NN>", &format!("{day:02}"))
+}
+
+/// Adds `day` to the `days!` table of `days.rs`, keeping it sorted.
+pub fn register(days_rs: &str, year: u16, day: u8) -> Result<String> {
+    let module = format!("d{day:02}");
+    let entry =
+        format!("    \"{}\" => {module}::Puzzle({day}),", feature(year, day));
+
+    if days_rs.contains(&format!("=> {module}::")) {
+        return Err(Error::Config(format!("{module} is already registered")));
+    }
+
+    let mut lines: Vec<String> = days_rs.lines().map(String::from).collect();
+    let table = block(&lines, "days! {", "}")
+        .ok_or(Error::Config("days.rs has no days! table".into()))?;
+    insert_sorted(&mut lines, table, entry, registered_day);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the cargo feature of `day` to `Cargo.toml` and to its year's
+/// feature, keeping both sorted.
+pub fn enable(cargo_toml: &str, year: u16, day: u8) -> Result<String> {
+    let feature = feature(year, day);
+    let prefix = format!("y{year}-d");
+
+    let mut lines: Vec<String> = cargo_toml.lines().map(String::from).collect();
+    if lines
+        .iter()
+        .any(|line| line.starts_with(&format!("{feature} =")))
+    {
+        return Err(Error::Config(format!("{feature} is already a feature")));
+    }
+
+    let list = block(&lines, &format!("y{year} = ["), "]").ok_or(
+        Error::Config(format!("Cargo.toml has no y{year} feature list")),
+    )?;
+    let after_list = list.end + 1;
+    insert_sorted(&mut lines, list, format!("    {feature:?},"), |line| {
+        Some(line.trim().to_string())
+    });
+
+    let features = after_list + 1..lines.len();
+    insert_sorted(&mut lines, features, format!("{feature} = []"), |line| {
+        let (name, _) = line.split_once(" = ")?;
+        name.starts_with(&prefix).then(|| name.to_string())
+    });
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The cargo feature compiling `day` of `year`.
+pub fn feature(year: u16, day: u8) -> String {
+    format!("y{year}-d{day:02}")
+}
+
+/// Lines strictly between the line equal to `open` and the next line equal
+/// to `close`.
+fn block(lines: &[String], open: &str, close: &str) -> Option<Range<usize>> {
+    let start = lines.iter().position(|line| line == open)? + 1;
+    let end = start + lines[start..].iter().position(|line| line == close)?;
+    Some(start..end)
+}
+
+/// Inserts `line` into `lines[range]` before the first line with a greater
+/// `key`, or after the last line with a key. Lines without a key, such as
+/// blank ones, are skipped.
+fn insert_sorted<K: Ord>(
+    lines: &mut Vec<String>,
+    range: Range<usize>,
+    line: String,
+    key: impl Fn(&str) -> Option<K>,
+) {
+    let new = key(&line);
+    let keyed: Vec<usize> = range
+        .clone()
+        .filter(|&i| key(&lines[i]).is_some())
+        .collect();
+    let at = keyed
+        .iter()
+        .copied()
+        .find(|&i| key(&lines[i]) > new)
+        .or(keyed.last().map(|i| i + 1))
+        .unwrap_or(range.start);
+    lines.insert(at, line);
+}
+
+/// The source file of `day` of `year` in the crate at `root`.
+pub fn module(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(YEARS_DIR)
+        .join(format!("y{year}"))
+        .join("days")
+        .join(format!("d{day:02}.rs"))
+}
+
+/// Creates `day` of `year` in the crate at `root`: its module, empty inputs,
+/// its registration and its cargo feature. Nothing is written if any of it
+/// already exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let module = module(root, year, day);
+    let days_dir = module.parent().unwrap().to_path_buf();
+    let inputs = root
+        .join(INPUTS_DIR)
+        .join(year.to_string())
+        .join(format!("{day:02}"));
+    let days_rs = days_dir.with_extension("rs");
+    if !days_rs.exists() {
+        return Err(Error::Config(format!(
+            "{} does not exist, year {year} has no module tree",
+            days_rs.display()
+        )));
+    }
+
+    let mut created = vec![module];
+    created.extend(InputKind::ALL.iter().map(|k| inputs.join(k.file_name())));
+    if let Some(path) = created.iter().find(|path| path.exists()) {
+        return Err(Error::Config(format!(
+            "{} already exists",
+            path.display()
+        )));
+    }
+
+    let cargo_toml = root.join("Cargo.toml");
+    let registered = register(&fs::read_to_string(&days_rs)?, year, day)?;
+    let enabled = enable(&fs::read_to_string(&cargo_toml)?, year, day)?;
+
+    fs::create_dir_all(&inputs)?;
+    fs::create_dir_all(&days_dir)?;
+    fs::write(&created[0], render(year, day))?;
+    for input in &created[1..] {
+        fs::write(input, "")?;
+    }
+    fs::write(&days_rs, registered)?;
+    fs::write(&cargo_toml, enabled)?;
+
+    created.push(days_rs);
+    created.push(cargo_toml);
+    Ok(created)
+}
+
+fn registered_day(line: &str) -> Option<u8> {
+    let (_, rest) = line.trim().rsplit_once('(')?;
+    rest.strip_suffix("),")?.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAYS_RS: &str = "\
+use crate::solution::days;
+
+days! {
+    \"y2025-d01\" => d01::Document(1),
+    \"y2025-d03\" => d03::Banks(3),
+}
+";
+
+    const CARGO_TOML: &str = "\
+[features]
+default = [\"all-days\"]
+all-days = [\"y2025\"]
+y2025 = [
+    \"y2025-d01\",
+    \"y2025-d03\",
+]
+y2025-d01 = []
+y2025-d03 = [\"dep:regex\"]
+
+embed-inputs = []
+";
+
+    #[test]
+    fn render_template() {
+        let code = render(2025, 7);
+        assert!(code.contains("mod d07 {"));
+        assert!(code.contains("`inputs/2025/07/`"));
+        assert!(!code.contains("<NN>") && !code.contains("<name>"));
+        assert!(code.contains("use crate::runner::UNSOLVED;"));
+    }
+
+    #[test]
+    fn register_in_order() {
+        let days_rs = register(DAYS_RS, 2025, 2).unwrap();
+        assert!(days_rs.contains(
+            "(1),\n    \"y2025-d02\" => d02::Puzzle(2),\n    \"y2025-d03\""
+        ));
+
+        let days_rs = register(DAYS_RS, 2025, 12).unwrap();
+        assert!(days_rs.contains("\"y2025-d12\" => d12::Puzzle(12),\n}"));
+    }
+
+    #[test]
+    fn refuse_registered_day() {
+        let error = register(DAYS_RS, 2025, 3).unwrap_err();
+        assert_eq!("d03 is already registered", error.to_string());
+    }
+
+    #[test]
+    fn enable_in_order() {
+        let cargo_toml = enable(CARGO_TOML, 2025, 2).unwrap();
+        assert!(cargo_toml.contains(
+            "    \"y2025-d01\",\n    \"y2025-d02\",\n    \"y2025-d03\",\n]"
+        ));
+        assert!(cargo_toml.contains(
+            "y2025-d01 = []\ny2025-d02 = []\ny2025-d03 = [\"dep:regex\"]\n"
+        ));
+
+        let cargo_toml = enable(CARGO_TOML, 2025, 10).unwrap();
+        assert!(cargo_toml.contains("\"y2025-d10\",\n]"));
+        assert!(cargo_toml.contains("y2025-d10 = []\n\nembed-inputs"));
+
+        let error = enable(CARGO_TOML, 2025, 3).unwrap_err();
+        assert_eq!("y2025-d03 is already a feature", error.to_string());
+    }
+
+    #[test]
+    fn create_day_once() {
+        let root = std::env::temp_dir()
+            .join(format!("advent_of_code_scaffold_{}", std::process::id()));
+        let year_dir = root.join("src/years/y2025");
+        fs::create_dir_all(&year_dir).unwrap();
+        fs::write(year_dir.join("days.rs"), DAYS_RS).unwrap();
+        fs::write(root.join("Cargo.toml"), CARGO_TOML).unwrap();
+
+        let created = new_day(&root, 2025, 4).unwrap();
+        assert_eq!(5, created.len());
+        assert!(root.join("inputs/2025/04/input.txt").exists());
+        assert_eq!(
+            render(2025, 4),
+            fs::read_to_string(year_dir.join("days/d04.rs")).unwrap()
+        );
+
+        let error = new_day(&root, 2025, 4).unwrap_err();
+        assert!(error.to_string().ends_with("d04.rs already exists"));
+
+        let error = new_day(&root, 2015, 1).unwrap_err();
+        assert!(error.to_string().ends_with("year 2015 has no module tree"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
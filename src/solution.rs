@@ -0,0 +1,279 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use std::any::Any;
+use std::str::FromStr;
+
+/// Name of the implementation `part_1` and `part_2` give.
+pub const MAIN: &str = "main";
+
+/// A day's puzzle: parsed once from the raw input, then asked for each part,
+/// possibly on another thread.
+pub trait Solution: FromStr<Err = Error> + Send + Sync + 'static {
+    /// Other implementations of either part, run next to `part_1` and
+    /// `part_2` by `verify` and `bench`, which must agree with them.
+    const VARIANTS: &'static [Variant<Self>] = &[];
+
+    fn part_1(&self) -> Answer;
+
+    fn part_2(&self) -> Answer;
+}
+
+/// A named alternative way of solving one part.
+pub struct Variant<S> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&S) -> Answer,
+}
+
+/// A registered day with its `Solution` erased so every day fits in one
+/// table.
+pub struct Day {
+    pub day: u8,
+    solver: Solver,
+}
+
+enum Solver {
+    Compiled {
+        parse: fn(&str) -> Result<Box<dyn Any + Send + Sync>>,
+        parts: [fn(&dyn Any) -> Answer; 2],
+        variants: Variants,
+    },
+    /// Left out of the build by turning off `feature`.
+    NotCompiled { feature: &'static str },
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(day: u8) -> Self {
+        Day {
+            day,
+            solver: Solver::Compiled {
+                parse: parse::<S>,
+                parts: [part_1::<S>, part_2::<S>],
+                variants: Variants {
+                    names: variant_names::<S>,
+                    solve: variant::<S>,
+                },
+            },
+        }
+    }
+
+    pub const fn not_compiled(day: u8, feature: &'static str) -> Self {
+        Day {
+            day,
+            solver: Solver::NotCompiled { feature },
+        }
+    }
+
+    /// The cargo feature that would bring this day into the build, if it is
+    /// not compiled.
+    pub fn missing_feature(&self) -> Option<&'static str> {
+        match self.solver {
+            Solver::Compiled { .. } => None,
+            Solver::NotCompiled { feature } => Some(feature),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        match self.solver {
+            Solver::Compiled {
+                parse,
+                parts,
+                variants,
+            } => Ok(Parsed {
+                day: self.day,
+                parts,
+                variants,
+                puzzle: parse(input)?,
+            }),
+            Solver::NotCompiled { feature } => Err(Error::Config(format!(
+                "day {} is not compiled, enable the `{feature}` feature",
+                self.day
+            ))),
+        }
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<Answer> {
+        Ok(self.parse(input)?.part(part))
+    }
+}
+
+/// The `VARIANTS` of a day's `Solution`, erased like its parts.
+#[derive(Clone, Copy)]
+struct Variants {
+    names: fn(u8) -> Vec<&'static str>,
+    solve: fn(&dyn Any, u8, &str) -> Option<Answer>,
+}
+
+/// A parsed input ready to run either part of its day.
+pub struct Parsed {
+    day: u8,
+    parts: [fn(&dyn Any) -> Answer; 2],
+    variants: Variants,
+    puzzle: Box<dyn Any + Send + Sync>,
+}
+
+impl Parsed {
+    pub fn part(&self, part: u8) -> Answer {
+        match part {
+            1 | 2 => (self.parts[part as usize - 1])(self.puzzle.as_ref()),
+            _ => panic!("day {} has no part {part}", self.day),
+        }
+    }
+
+    /// Every implementation of `part`, `MAIN` first.
+    pub fn variants(&self, part: u8) -> Vec<&'static str> {
+        let mut names = vec![MAIN];
+        names.extend((self.variants.names)(part));
+        names
+    }
+
+    pub fn variant(&self, part: u8, name: &str) -> Answer {
+        if name == MAIN {
+            return self.part(part);
+        }
+        (self.variants.solve)(self.puzzle.as_ref(), part, name).unwrap_or_else(
+            || panic!("day {} has no variant {name} of part {part}", self.day),
+        )
+    }
+}
+
+/// Declares a year's day modules, each behind its own cargo feature, and
+/// the `DAYS` table listing them. Days whose feature is off stay in the
+/// table as not compiled.
+macro_rules! days {
+    ($($feature:literal => $module:ident::$solution:ident($day:literal),)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            pub mod $module;
+        )*
+
+        pub const DAYS: &[$crate::solution::Day] = &[$(
+            #[cfg(feature = $feature)]
+            $crate::solution::Day::new::<$module::$solution>($day),
+            #[cfg(not(feature = $feature))]
+            $crate::solution::Day::not_compiled($day, $feature),
+        )*];
+    };
+}
+
+pub(crate) use days;
+
+fn parse<S: Solution + 'static>(
+    input: &str,
+) -> Result<Box<dyn Any + Send + Sync>> {
+    Ok(Box::new(input.parse::<S>()?))
+}
+
+fn part_1<S: Solution + 'static>(puzzle: &dyn Any) -> Answer {
+    downcast::<S>(puzzle).part_1()
+}
+
+fn part_2<S: Solution + 'static>(puzzle: &dyn Any) -> Answer {
+    downcast::<S>(puzzle).part_2()
+}
+
+fn variant_names<S: Solution>(part: u8) -> Vec<&'static str> {
+    S::VARIANTS
+        .iter()
+        .filter(|v| v.part == part)
+        .map(|v| v.name)
+        .collect()
+}
+
+fn variant<S: Solution + 'static>(
+    puzzle: &dyn Any,
+    part: u8,
+    name: &str,
+) -> Option<Answer> {
+    let variant = S::VARIANTS
+        .iter()
+        .find(|v| v.part == part && v.name == name)?;
+    Some((variant.solve)(downcast::<S>(puzzle)))
+}
+
+fn downcast<S: 'static>(puzzle: &dyn Any) -> &S {
+    puzzle
+        .downcast_ref::<S>()
+        .expect("puzzle was parsed by the same day")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::parse_number;
+
+    struct Sum(Vec<u32>);
+
+    impl FromStr for Sum {
+        type Err = Error;
+
+        fn from_str(input: &str) -> Result<Self> {
+            input
+                .split(',')
+                .map(|n| parse_number(input, n, 1))
+                .collect::<Result<_>>()
+                .map(Sum)
+        }
+    }
+
+    impl Solution for Sum {
+        const VARIANTS: &'static [Variant<Self>] = &[Variant {
+            part: 1,
+            name: "rev",
+            solve: |sum| sum.0.iter().rev().sum::<u32>().into(),
+        }];
+
+        fn part_1(&self) -> Answer {
+            self.0.iter().sum::<u32>().into()
+        }
+
+        fn part_2(&self) -> Answer {
+            self.0.len().into()
+        }
+    }
+
+    #[test]
+    fn solve_erased_day() {
+        let day = Day::new::<Sum>(1);
+        assert_eq!(6, day.solve(1, "1,2,3").unwrap());
+        assert_eq!(3, day.solve(2, "1,2,3").unwrap());
+    }
+
+    #[test]
+    fn parse_error_reaches_caller() {
+        let day = Day::new::<Sum>(1);
+        let error = day.solve(1, "1,x").unwrap_err();
+        assert_eq!("line 1, column 3: bad number \"x\"", error.to_string());
+    }
+
+    #[test]
+    fn not_compiled_day() {
+        let day = Day::not_compiled(6, "y2025-d06");
+        assert_eq!(Some("y2025-d06"), day.missing_feature());
+        assert_eq!(None, Day::new::<Sum>(1).missing_feature());
+        assert_eq!(
+            "day 6 is not compiled, enable the `y2025-d06` feature",
+            day.solve(1, "1").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn parse_once_run_both_parts() {
+        let day = Day::new::<Sum>(1);
+        let parsed = day.parse("4,5").unwrap();
+        assert_eq!(9, parsed.part(1));
+        assert_eq!(2, parsed.part(2));
+    }
+
+    #[test]
+    fn run_named_variants() {
+        let parsed = Day::new::<Sum>(1).parse("4,5").unwrap();
+        assert_eq!(vec![MAIN, "rev"], parsed.variants(1));
+        assert_eq!(vec![MAIN], parsed.variants(2));
+        assert_eq!(9, parsed.variant(1, "rev"));
+        assert_eq!(2, parsed.variant(2, MAIN));
+    }
+}
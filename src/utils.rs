@@ -1,3 +1,9 @@
+pub mod dag;
+pub mod dsu;
+pub mod geom;
+pub mod grid;
+pub mod intervals;
+
 pub fn floor_n(x: i32, n: i32) -> i32 {
     if x >= 0 {
         x - x % n
@@ -0,0 +1,286 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+/// A directed graph of nodes `0..len` meant to have no cycles. Edges can
+/// be added in any order; whatever needs the graph acyclic finds out when
+/// it sorts it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dag {
+    children: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DagError {
+    /// The graph was not acyclic after all.
+    Cycle,
+    /// More paths than a u128 holds.
+    Overflow,
+}
+
+impl fmt::Display for DagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DagError::Cycle => write!(f, "graph has a cycle"),
+            DagError::Overflow => write!(f, "more paths than fit in a u128"),
+        }
+    }
+}
+
+impl std::error::Error for DagError {}
+
+impl Dag {
+    /// `len` nodes and no edges.
+    pub fn new(len: usize) -> Self {
+        Dag {
+            children: vec![Vec::new(); len],
+        }
+    }
+
+    /// Adds a node without edges, returning its id.
+    pub fn add_node(&mut self) -> usize {
+        self.children.push(Vec::new());
+        self.children.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        assert!(to < self.len(), "no node {to}");
+        self.children[from].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn children(&self, node: usize) -> &[usize] {
+        &self.children[node]
+    }
+
+    /// Nodes no edge leads to.
+    pub fn sources(&self) -> Vec<usize> {
+        let in_degrees = self.in_degrees();
+        (0..self.len()).filter(|n| in_degrees[*n] == 0).collect()
+    }
+
+    /// Nodes no edge leaves.
+    pub fn sinks(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|n| self.children[*n].is_empty())
+            .collect()
+    }
+
+    fn in_degrees(&self) -> Vec<usize> {
+        let mut in_degrees = vec![0; self.len()];
+        for child in self.children.iter().flatten() {
+            in_degrees[*child] += 1;
+        }
+        in_degrees
+    }
+
+    /// Every node, each before all the nodes its edges lead to.
+    pub fn topological_order(&self) -> Result<Vec<usize>, DagError> {
+        let mut in_degrees = self.in_degrees();
+        let mut ready: VecDeque<usize> =
+            (0..self.len()).filter(|n| in_degrees[*n] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for child in &self.children[node] {
+                in_degrees[*child] -= 1;
+                if in_degrees[*child] == 0 {
+                    ready.push_back(*child);
+                }
+            }
+        }
+
+        // Nodes on a cycle never run out of edges leading to them.
+        if order.len() < self.len() {
+            return Err(DagError::Cycle);
+        }
+        Ok(order)
+    }
+
+    /// How many paths lead from any of `sources` to each node. A source
+    /// counts as one path to itself.
+    pub fn path_counts(
+        &self,
+        sources: impl IntoIterator<Item = usize>,
+    ) -> Result<Vec<u128>, DagError> {
+        let mut counts = vec![0u128; self.len()];
+        for source in sources {
+            counts[source] += 1;
+        }
+        for node in self.topological_order()? {
+            let count = counts[node];
+            if count == 0 {
+                continue;
+            }
+            for child in &self.children[node] {
+                counts[*child] = counts[*child]
+                    .checked_add(count)
+                    .ok_or(DagError::Overflow)?;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// How many paths lead from any of `sources` to any sink.
+    pub fn count_paths(
+        &self,
+        sources: impl IntoIterator<Item = usize>,
+    ) -> Result<u128, DagError> {
+        let counts = self.path_counts(sources)?;
+        self.sinks().into_iter().try_fold(0u128, |total, sink| {
+            total.checked_add(counts[sink]).ok_or(DagError::Overflow)
+        })
+    }
+
+    /// The fewest edges on a path from `from` to each node, none for nodes
+    /// it does not reach.
+    pub fn shortest_paths(
+        &self,
+        from: usize,
+    ) -> Result<Vec<Option<usize>>, DagError> {
+        self.paths(from, usize::min)
+    }
+
+    /// The most edges on a path from `from` to each node, none for nodes it
+    /// does not reach.
+    pub fn longest_paths(
+        &self,
+        from: usize,
+    ) -> Result<Vec<Option<usize>>, DagError> {
+        self.paths(from, usize::max)
+    }
+
+    /// Path lengths from `from`, keeping whichever of two lengths to the same
+    /// node `pick` picks.
+    fn paths(
+        &self,
+        from: usize,
+        pick: fn(usize, usize) -> usize,
+    ) -> Result<Vec<Option<usize>>, DagError> {
+        let mut lengths = vec![None; self.len()];
+        lengths[from] = Some(0);
+        for node in self.topological_order()? {
+            let Some(length) = lengths[node] else {
+                continue;
+            };
+            for child in &self.children[node] {
+                let through = length + 1;
+                lengths[*child] =
+                    Some(lengths[*child].map_or(through, |l| pick(l, through)));
+            }
+        }
+        Ok(lengths)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0 → 1 → 3 → 4, 0 → 2 → 3, 0 → 4, and 5 on its own.
+    fn diamond() -> Dag {
+        let mut dag = Dag::new(5);
+        for (from, to) in [(0, 1), (1, 3), (3, 4), (0, 2), (2, 3), (0, 4)] {
+            dag.add_edge(from, to);
+        }
+        assert_eq!(5, dag.add_node());
+        dag
+    }
+
+    #[test]
+    fn sort_and_ends() {
+        let dag = diamond();
+        assert_eq!(Ok(vec![0, 5, 1, 2, 3, 4]), dag.topological_order());
+        assert_eq!(vec![0, 5], dag.sources());
+        assert_eq!(vec![4, 5], dag.sinks());
+        assert_eq!(&[1, 2, 4], dag.children(0));
+    }
+
+    #[test]
+    fn cycles_detected() {
+        let mut dag = diamond();
+        dag.add_edge(4, 1);
+        assert_eq!(Err(DagError::Cycle), dag.topological_order());
+        assert_eq!(Err(DagError::Cycle), dag.count_paths([0]));
+        assert_eq!(Err(DagError::Cycle), dag.longest_paths(0));
+
+        let mut looped = Dag::new(1);
+        looped.add_edge(0, 0);
+        assert_eq!(
+            "graph has a cycle",
+            looped.topological_order().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn count_paths() {
+        let dag = diamond();
+        assert_eq!(Ok(vec![1, 1, 1, 2, 3, 0]), dag.path_counts([0]));
+        assert_eq!(Ok(3), dag.count_paths([0]));
+        assert_eq!(Ok(4), dag.count_paths(dag.sources()));
+    }
+
+    /// A chain of `layers` diamonds from node 0, each doubling the paths,
+    /// and the node at its end.
+    fn doubling(layers: usize) -> (Dag, usize) {
+        let mut dag = Dag::new(1);
+        let mut last = 0;
+        for _ in 0..layers {
+            let (a, b, next) = (dag.add_node(), dag.add_node(), dag.add_node());
+            for (from, to) in [(last, a), (last, b), (a, next), (b, next)] {
+                dag.add_edge(from, to);
+            }
+            last = next;
+        }
+        (dag, last)
+    }
+
+    #[test]
+    fn many_paths_fit() {
+        let (dag, _) = doubling(100);
+        assert_eq!(Ok(1 << 100), dag.count_paths([0]));
+    }
+
+    #[test]
+    fn too_many_paths() {
+        // 2^127 paths reach each of two sinks, which fit alone but not
+        // together.
+        let (mut dag, last) = doubling(127);
+        let (left, right) = (dag.add_node(), dag.add_node());
+        dag.add_edge(last, left);
+        dag.add_edge(last, right);
+        assert_eq!(Ok(1 << 127), dag.path_counts([0]).map(|c| c[left]));
+        assert_eq!(Err(DagError::Overflow), dag.count_paths([0]));
+
+        // Joining the sinks overflows a single node.
+        dag.add_edge(left, right);
+        assert_eq!(Err(DagError::Overflow), dag.path_counts([0]));
+        assert_eq!(
+            "more paths than fit in a u128",
+            DagError::Overflow.to_string()
+        );
+    }
+
+    #[test]
+    fn shortest_and_longest() {
+        let dag = diamond();
+        assert_eq!(
+            Ok(vec![Some(0), Some(1), Some(1), Some(2), Some(1), None]),
+            dag.shortest_paths(0)
+        );
+        assert_eq!(
+            Ok(vec![Some(0), Some(1), Some(1), Some(2), Some(3), None]),
+            dag.longest_paths(0)
+        );
+        assert_eq!(
+            Ok(vec![None, None, None, Some(0), Some(1), None]),
+            dag.longest_paths(3)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+/// Elements `0..len` grouped into components that can only be joined,
+/// never split.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    /// Only meaningful for roots.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// `len` elements, each in a component of its own.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The element standing for the component of `x`. Everything passed on
+    /// the way is pointed straight at it, so later lookups are quicker.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        let mut x = x;
+        while x != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Joins the components of `a` and `b`, the smaller under the larger.
+    /// False if they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// How many elements share a component with `x`, itself included.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The elements of every component, each in order, and the components
+    /// ordered by their first element.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> {
+        let mut components: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut firsts = vec![None; self.len()];
+        for x in 0..self.len() {
+            let root = self.root(x);
+            let first = *firsts[root].get_or_insert(x);
+            components.entry(first).or_default().push(x);
+        }
+        components.into_values()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn union_and_find() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(6, set.component_count());
+        assert!(set.union(0, 3));
+        assert!(set.union(4, 3));
+        assert!(!set.union(0, 4));
+        assert!(set.union(1, 5));
+        assert!(set.same(4, 0));
+        assert!(!set.same(1, 0));
+        assert_eq!(3, set.component_count());
+        assert_eq!(3, set.size_of(4));
+        assert_eq!(2, set.size_of(5));
+        assert_eq!(1, set.size_of(2));
+    }
+
+    #[test]
+    fn components_in_order() {
+        let mut set = DisjointSet::new(5);
+        set.union(4, 1);
+        set.union(3, 0);
+        set.union(0, 4);
+        assert_eq!(
+            vec![vec![0, 1, 3, 4], vec![2]],
+            set.components().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn chain_stays_flat() {
+        let len = 100_000;
+        let mut set = DisjointSet::new(len);
+        for x in 1..len {
+            set.union(x - 1, x);
+        }
+        assert_eq!(1, set.component_count());
+        assert_eq!(len, set.size_of(0));
+        let root = set.find(len - 1);
+        assert!((0..len).all(|x| set.parent[x] == root));
+    }
+}
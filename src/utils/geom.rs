@@ -0,0 +1,311 @@
+use crate::error::{
+    parse_number,
+    Error,
+    Result,
+};
+use std::fmt;
+use std::ops::{
+    Add,
+    AddAssign,
+    Mul,
+    Neg,
+    Sub,
+    SubAssign,
+};
+use std::str::FromStr;
+
+/// What distances and bounding boxes need of a coordinate. Every primitive
+/// integer has it.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Coordinate for T where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+}
+
+/// How far apart `a` and `b` are, without going below zero for unsigned
+/// coordinates.
+fn delta<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// The smallest box, edges included, holding a set of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+/// Points compared axis by axis, which is all `Bounds` needs of them.
+pub trait Componentwise: Copy + Sub<Output = Self> {
+    /// The smaller of each coordinate.
+    fn lower(self, other: Self) -> Self;
+
+    /// The larger of each coordinate.
+    fn upper(self, other: Self) -> Self;
+
+    /// Whether no coordinate is larger than in `other`.
+    fn all_le(&self, other: &Self) -> bool;
+}
+
+impl<P: Componentwise> Bounds<P> {
+    /// The bounds of `points`, or none if there are none.
+    pub fn of(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        for point in points {
+            bounds.extend(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds just enough to hold `point`.
+    pub fn extend(&mut self, point: P) {
+        self.min = self.min.lower(point);
+        self.max = self.max.upper(point);
+    }
+
+    pub fn contains(&self, point: &P) -> bool {
+        self.min.all_le(point) && point.all_le(&self.max)
+    }
+
+    /// How far the bounds reach along each axis, so a single point has a
+    /// size of zero.
+    pub fn size(&self) -> P {
+        self.max - self.min
+    }
+}
+
+macro_rules! point {
+    ($name:ident, $dimensions:literal, $first:ident $(, $axis:ident)*) => {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name<T> {
+            pub $first: T,
+            $(pub $axis: T,)*
+        }
+
+        impl<T> $name<T> {
+            pub const fn new($first: T $(, $axis: T)*) -> Self {
+                $name { $first $(, $axis)* }
+            }
+        }
+
+        impl<T: Coordinate> $name<T> {
+            /// The square of the straight line distance, which orders points
+            /// the same way without needing a square root.
+            pub fn distance_squared(&self, other: &Self) -> T {
+                let d = delta(self.$first, other.$first);
+                d * d $(+ {
+                    let d = delta(self.$axis, other.$axis);
+                    d * d
+                })*
+            }
+
+            /// The distance moving only along the axes.
+            pub fn manhattan(&self, other: &Self) -> T {
+                delta(self.$first, other.$first)
+                    $(+ delta(self.$axis, other.$axis))*
+            }
+
+            /// The distance moving along the axes and diagonals alike.
+            pub fn chebyshev(&self, other: &Self) -> T {
+                delta(self.$first, other.$first)
+                    $(.max(delta(self.$axis, other.$axis)))*
+            }
+        }
+
+        impl<T: FromStr> $name<T> {
+            #[doc = concat!(
+                "Reads ", $dimensions, " comma separated numbers making up ",
+                "the whole of `line`, which is line `line_number` of the input."
+            )]
+            pub fn parse(line: &str, line_number: usize) -> Result<Self> {
+                let mut parts = line.split(',');
+                let mut next = |axis| match parts.next() {
+                    Some(n) => parse_number(line, n, line_number),
+                    None => {
+                        let column = line.len() + 1;
+                        Err(Error::missing(axis, line_number, column))
+                    }
+                };
+                let point = $name {
+                    $first: next(concat!(stringify!($first), " coordinate"))?,
+                    $($axis: next(concat!(stringify!($axis), " coordinate"))?,)*
+                };
+                match parts.next() {
+                    Some(extra) => {
+                        let column = line.len() - extra.len();
+                        Err(Error::unexpected_char(',', line_number, column))
+                    }
+                    None => Ok(point),
+                }
+            }
+        }
+
+        impl<T: Coordinate> Componentwise for $name<T> {
+            fn lower(self, other: Self) -> Self {
+                $name {
+                    $first: self.$first.min(other.$first),
+                    $($axis: self.$axis.min(other.$axis),)*
+                }
+            }
+
+            fn upper(self, other: Self) -> Self {
+                $name {
+                    $first: self.$first.max(other.$first),
+                    $($axis: self.$axis.max(other.$axis),)*
+                }
+            }
+
+            fn all_le(&self, other: &Self) -> bool {
+                self.$first <= other.$first $(&& self.$axis <= other.$axis)*
+            }
+        }
+
+        impl<T> From<[T; $dimensions]> for $name<T> {
+            fn from([$first $(, $axis)*]: [T; $dimensions]) -> Self {
+                $name { $first $(, $axis)* }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $name {
+                    $first: self.$first + other.$first,
+                    $($axis: self.$axis + other.$axis,)*
+                }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $name {
+                    $first: self.$first - other.$first,
+                    $($axis: self.$axis - other.$axis,)*
+                }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, scale: T) -> Self {
+                $name {
+                    $first: self.$first * scale,
+                    $($axis: self.$axis * scale,)*
+                }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name { $first: -self.$first $(, $axis: -self.$axis)* }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.$first)?;
+                $(write!(f, ",{}", self.$axis)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+point!(Point2, 2, x, y);
+point!(Point3, 3, x, y, z);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(1, -2);
+        let mut b = Point2::new(4, 6);
+        assert_eq!(Point2::new(5, 4), a + b);
+        assert_eq!(Point2::new(3, 8), b - a);
+        assert_eq!(Point2::new(-3, 6), -a * 3);
+        b -= a;
+        b += Point2::from([0, 1]);
+        assert_eq!(Point2::new(3, 9), b);
+        assert_eq!("3,9", b.to_string());
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point3::new(162i64, 817, 812);
+        let b = Point3::new(425, 690, 689);
+        assert_eq!(100427, a.distance_squared(&b));
+        assert_eq!(513, a.manhattan(&b));
+        assert_eq!(263, a.chebyshev(&b));
+
+        // Unsigned coordinates measure the same whichever way round.
+        let c = Point2::new(2u64, 9);
+        let d = Point2::new(7u64, 1);
+        assert_eq!(89, c.distance_squared(&d));
+        assert_eq!(d.manhattan(&c), c.manhattan(&d));
+        assert_eq!(8, d.chebyshev(&c));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points =
+            [Point2::new(7u64, 1), Point2::new(11, 7), Point2::new(2, 3)];
+        let bounds = Bounds::of(points).unwrap();
+        assert_eq!(Point2::new(2, 1), bounds.min);
+        assert_eq!(Point2::new(11, 7), bounds.max);
+        assert_eq!(Point2::new(9, 6), bounds.size());
+        assert!(bounds.contains(&Point2::new(2, 7)));
+        assert!(!bounds.contains(&Point2::new(12, 7)));
+        assert_eq!(None, Bounds::<Point3<i32>>::of([]));
+    }
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(Point2::new(7u64, 1), Point2::parse("7,1", 1).unwrap());
+        assert_eq!(
+            Point3::new(-57i64, 618, 0),
+            Point3::parse("-57,618,0", 1).unwrap()
+        );
+
+        let error =
+            |line| Point3::<i64>::parse(line, 2).unwrap_err().to_string();
+        assert_eq!("line 2, column 7: missing z coordinate", error("57,618"));
+        assert_eq!("line 2, column 4: bad number \"x\"", error("57,x,1"));
+        assert_eq!(
+            "line 2, column 9: unexpected character ','",
+            error("57,618,1,4")
+        );
+    }
+}
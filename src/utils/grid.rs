@@ -0,0 +1,327 @@
+use crate::error::{
+    Error,
+    Result,
+};
+use std::fmt;
+use std::ops::{
+    Index,
+    IndexMut,
+};
+
+/// A rectangle of cells stored row by row, addressed by `(row, column)`
+/// from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid of `cells` given row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![cell; width * height])
+    }
+
+    /// Reads a character map, one row per line, turning each character into
+    /// a cell with `cell`. A character `cell` gives nothing for is an error,
+    /// as are rows of different lengths and an empty map.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(Error::missing("grid", 1, 1));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (row, line) in lines.iter().enumerate() {
+            let mut columns = 0;
+            for (column, c) in line.chars().enumerate() {
+                if column == width {
+                    return Err(Error::unexpected_char(c, row + 1, column + 1));
+                }
+                let found = cell(c).ok_or_else(|| {
+                    Error::unexpected_char(c, row + 1, column + 1)
+                })?;
+                cells.push(found);
+                columns += 1;
+            }
+            if columns < width {
+                return Err(Error::missing("cell", row + 1, columns + 1));
+            }
+        }
+
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        self.index_of(row, column).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        self.index_of(row, column).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.height && column < self.width)
+            .then_some(row * self.width + column)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |c| (row, c)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions above, left, right and below `position` that are on
+    /// the grid.
+    pub fn neighbours_4(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + use<T> {
+        self.offsets(position, &NEIGHBOURS_4)
+    }
+
+    /// The positions around `position`, diagonals included, that are on the
+    /// grid.
+    pub fn neighbours_8(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + use<T> {
+        self.offsets(position, &NEIGHBOURS_8)
+    }
+
+    fn offsets(
+        &self,
+        (row, column): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(dr, dc)| {
+            let row = row.checked_add_signed(*dr).filter(|r| *r < height)?;
+            let column =
+                column.checked_add_signed(*dc).filter(|c| *c < width)?;
+            Some((row, column))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row, top to bottom. A grid without columns still has its rows,
+    /// all empty.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of `column`, top to bottom.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "no column {column}");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Adds `row`, which must be as wide as the grid, at the bottom.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let before = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(self.width, self.cells.len() - before, "row of wrong width");
+        self.height += 1;
+    }
+
+    /// Rows become columns: the cell at `(r, c)` moves to `(c, r)`.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.collect::<Vec<_>>().into_iter().rev())
+            .cloned()
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_char_map() {
+        let grid = digits("123\n456\n\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get((1, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(4, grid[(1, 0)]);
+        assert_eq!("123\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| digits(input).unwrap_err().to_string();
+        assert_eq!(
+            "line 2, column 2: unexpected character 'x'",
+            error("12\n3x")
+        );
+        assert_eq!(
+            "line 2, column 3: unexpected character '5'",
+            error("12\n345")
+        );
+        assert_eq!("line 2, column 2: missing cell", error("12\n3"));
+        assert_eq!("line 1, column 1: missing grid", error("\n"));
+    }
+
+    #[test]
+    fn neighbours_stay_on_grid() {
+        let grid = Grid::filled(3, 3, 0);
+        let corner: Vec<_> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(vec![(0, 1), (1, 0)], corner);
+        assert_eq!(3, grid.neighbours_8((0, 0)).count());
+        assert_eq!(5, grid.neighbours_8((2, 1)).count());
+        assert_eq!(8, grid.neighbours_8((1, 1)).count());
+        assert_eq!(4, grid.neighbours_4((1, 1)).count());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+        let sums: Vec<u32> = grid.columns().map(|c| c.sum()).collect();
+        assert_eq!(vec![5, 7, 9], sums);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!("14\n25\n36\n", grid.transpose().to_string());
+        assert_eq!("41\n52\n63\n", grid.rotate().to_string());
+        assert_eq!(grid, grid.rotate().rotate().rotate().rotate());
+    }
+
+    #[test]
+    fn empty_grids() {
+        let empty: Grid<u32> = Grid::new(0, 0, vec![]);
+        assert_eq!(0, empty.rows().count());
+        assert_eq!("", empty.to_string());
+        assert_eq!(0, empty.positions().count());
+
+        let narrow = Grid::filled(0, 2, 7);
+        assert!(narrow.rows().map(<[i32]>::len).eq([0, 0]));
+        assert_eq!("\n\n", narrow.to_string());
+        assert_eq!(Grid::new(2, 0, vec![]), narrow.transpose());
+        assert_eq!(narrow, narrow.transpose().transpose());
+    }
+
+    #[test]
+    fn grow_and_change() {
+        let mut grid = digits("12").unwrap();
+        grid.push_row([3, 4]);
+        grid[(1, 0)] = 9;
+        *grid.get_mut((0, 1)).unwrap() += 5;
+        assert_eq!("17\n94\n", grid.to_string());
+        let ones: Vec<_> = grid
+            .iter()
+            .filter(|(_, n)| **n > 5)
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(vec![(0, 1), (1, 0)], ones);
+    }
+}
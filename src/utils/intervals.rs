@@ -0,0 +1,235 @@
+use std::ops::RangeInclusive;
+
+/// Integers that can bound an `IntervalSet`.
+pub trait Endpoint: Copy + Ord {
+    fn succ(self) -> Option<Self>;
+
+    fn pred(self) -> Option<Self>;
+
+    /// How many integers `start..=end` holds, `start <= end`.
+    fn width(start: Self, end: Self) -> u128;
+}
+
+macro_rules! endpoint {
+    ($($int:ty),*) => {
+        $(
+            impl Endpoint for $int {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn width(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+endpoint!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers kept as sorted inclusive ranges that neither overlap nor
+/// touch, so each stretch of the set is exactly one range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Adds every integer of `range`, merging it with the ranges it overlaps
+    /// or touches. An empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let from = self
+            .ranges
+            .partition_point(|r| r.end().succ().is_some_and(|e| e < start));
+        let to = self
+            .ranges
+            .partition_point(|r| end.succ().is_none_or(|e| *r.start() <= e));
+        if from < to {
+            start = start.min(*self.ranges[from].start());
+            end = end.max(*self.ranges[to - 1].end());
+        }
+        self.ranges.splice(from..to, [start..=end]);
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < value);
+        self.ranges.get(i).is_some_and(|r| r.start() <= value)
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|r| T::width(*r.start(), *r.end()))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges missing between the first and last range of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        // Ranges never touch, so there is room on both sides of each gap.
+        self.ranges.windows(2).map(|pair| {
+            pair[0].end().succ().unwrap()..=pair[1].start().pred().unwrap()
+        })
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            let start = *a[i].start().max(b[j].start());
+            let end = *a[i].end().min(b[j].end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            if a[i].end() < b[j].end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// What is in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let cuts = &other.ranges;
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let (mut start, end) = (*range.start(), *range.end());
+            while j < cuts.len() && *cuts[j].end() < start {
+                j += 1;
+            }
+
+            let mut rest = true;
+            for cut in cuts[j..].iter().take_while(|c| *c.start() <= end) {
+                if *cut.start() > start {
+                    ranges.push(start..=cut.start().pred().unwrap());
+                }
+                match cut.end().succ() {
+                    Some(next) if next <= end => start = next,
+                    _ => {
+                        rest = false;
+                        break;
+                    }
+                }
+            }
+            if rest {
+                ranges.push(start..=end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let merged = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(&[3..=5, 10..=20], merged.ranges());
+        assert_eq!(14, merged.len());
+
+        // Touching ranges merge too, empty ones are dropped.
+        let empty = RangeInclusive::new(9, 8);
+        let touching = set(&[1..=2, 6..=7, 3..=5, empty.clone()]);
+        assert_eq!(&[1..=7], touching.ranges());
+        assert!(set(&[empty]).is_empty());
+    }
+
+    #[test]
+    fn contains_and_gaps() {
+        let s = set(&[3..=5, 10..=14, 20..=20]);
+        assert!(s.contains(&3) && s.contains(&14) && s.contains(&20));
+        assert!(!s.contains(&2) && !s.contains(&6) && !s.contains(&21));
+        assert_eq!(vec![6..=9, 15..=19], s.gaps().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=24, 30..=40]);
+        assert_eq!(set(&[0..=40]), a.union(&b));
+        assert_eq!(set(&[5..=10, 20..=24, 30..=30]), a.intersection(&b));
+        assert_eq!(set(&[0..=4, 25..=29]), a.difference(&b));
+        assert_eq!(set(&[11..=19, 31..=40]), b.difference(&a));
+        assert_eq!(a, a.difference(&IntervalSet::new()));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn extremes() {
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(u64::MAX as u128 + 1, full.len());
+        let middle: IntervalSet<u64> = [1..=u64::MAX - 1].into_iter().collect();
+        assert_eq!(
+            vec![0..=0, u64::MAX..=u64::MAX],
+            full.difference(&middle).iter().collect::<Vec<_>>()
+        );
+        let mut top = middle.clone();
+        top.insert(u64::MAX..=u64::MAX);
+        assert_eq!(&[1..=u64::MAX], top.ranges());
+    }
+}
@@ -0,0 +1,151 @@
+//! `watch`: re-verifies one day whenever its source, inputs or answers
+//! change, by polling their modification times.
+
+use crate::input::{
+    InputKind,
+    Locator,
+};
+use crate::scaffold;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::process::Command;
+use std::thread;
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+/// Modification time and size of every watched file, missing files
+/// included, so any change to one compares unequal.
+pub type Snapshot = Vec<(PathBuf, Option<(SystemTime, u64)>)>;
+
+/// The files `day` of `year` depends on: its module, whatever is in its input
+/// directory, and the year's answers.
+pub fn watched(
+    root: &Path,
+    locator: &Locator,
+    year: u16,
+    day: u8,
+) -> Vec<PathBuf> {
+    let mut paths =
+        vec![scaffold::module(root, year, day), locator.answers(year)];
+
+    let inputs = locator.path(year, day, InputKind::Example);
+    let inputs = inputs.parent().unwrap();
+    let mut files: Vec<PathBuf> = fs::read_dir(inputs)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .collect();
+    if files.is_empty() {
+        files = InputKind::ALL
+            .iter()
+            .map(|kind| locator.path(year, day, *kind))
+            .collect();
+    }
+    files.sort();
+    paths.extend(files);
+    paths
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| {
+            let stamp = fs::metadata(path)
+                .and_then(|m| Ok((m.modified()?, m.len())))
+                .ok();
+            (path.clone(), stamp)
+        })
+        .collect()
+}
+
+/// Rebuilds the crate at `root` with only `day` compiled and verifies it,
+/// then waits for one of its files to change and does it again, until
+/// interrupted.
+pub fn watch(
+    root: &Path,
+    locator: &Locator,
+    year: u16,
+    day: u8,
+    interval: Duration,
+) -> Result<(), String> {
+    let mut last = None;
+    loop {
+        let current = snapshot(&watched(root, locator, year, day));
+        if last.as_ref() != Some(&current) {
+            last = Some(current);
+            // Clear the screen so only the latest run shows.
+            print!("\x1b[2J\x1b[H");
+            println!("watching day {day} of {year}, ctrl-c to stop\n");
+            let passed = verify(root, locator, year, day)?;
+            println!("\nday {day}: {}", if passed { "pass" } else { "FAIL" });
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Whether `cargo run -- verify` of `day` built and passed.
+fn verify(
+    root: &Path,
+    locator: &Locator,
+    year: u16,
+    day: u8,
+) -> Result<bool, String> {
+    let cargo = env::var_os("CARGO").unwrap_or(OsString::from("cargo"));
+    let status = Command::new(cargo)
+        .arg("run")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"))
+        .args(["--no-default-features", "--features"])
+        .arg(scaffold::feature(year, day))
+        .args(["--", "--year", &year.to_string(), "--input-dir"])
+        .arg(locator.dir())
+        .args(["verify", &day.to_string()])
+        .status()
+        .map_err(|e| format!("cannot run cargo: {e}"))?;
+    Ok(status.success())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn snapshot_sees_changes() {
+        let dir = env::temp_dir()
+            .join(format!("advent_of_code_watch_{}", std::process::id()));
+        let locator = Locator::new(dir.join("inputs"));
+        let watched = || watched(&dir, &locator, 2025, 3);
+
+        let before = snapshot(&watched());
+        assert_eq!(
+            vec![
+                dir.join("src/years/y2025/days/d03.rs"),
+                dir.join("inputs/2025/answers.toml"),
+                dir.join("inputs/2025/03/example.txt"),
+                dir.join("inputs/2025/03/input.txt"),
+            ],
+            watched()
+        );
+        assert!(before.iter().all(|(_, stamp)| stamp.is_none()));
+
+        let example = locator.path(2025, 3, InputKind::Example);
+        fs::create_dir_all(example.parent().unwrap()).unwrap();
+        fs::write(&example, "987654321111111\n").unwrap();
+        let after = snapshot(&watched());
+        assert_ne!(before, after);
+        assert_eq!(after, snapshot(&watched()));
+
+        fs::write(&example, "811111111111119\n987\n").unwrap();
+        assert_ne!(after, snapshot(&watched()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use crate::cancel::{
+    self,
+    Cancelled,
+    Token,
+};
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{
+    self,
+    AssertUnwindSafe,
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::sync::mpsc;
+use std::sync::Once;
+use std::thread;
+use std::time::Duration;
+
+/// Why a part gave no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Its input could not be read or parsed, or it panicked.
+    Error(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "{e}"),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {timeout:?}")
+            }
+        }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(e: String) -> Self {
+        Failure::Error(e)
+    }
+}
+
+thread_local! {
+    /// How many `isolate` calls the current thread is inside.
+    static ISOLATED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Wraps the panic hook once so that panics inside `isolate` stay quiet,
+/// their message being reported with the result instead. Panics anywhere
+/// else still go to the previous hook.
+fn quiet_isolated_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATED.get() == 0 {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error carrying its message.
+pub fn isolate<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    quiet_isolated_panics();
+    ISOLATED.set(ISOLATED.get() + 1);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.set(ISOLATED.get() - 1);
+    result.map_err(|payload| format!("panicked: {}", message(payload.as_ref())))
+}
+
+/// Runs `f` isolated like `isolate`, on a thread of its own when there is a
+/// `timeout`. Once that passes, `f` is left behind with its token cancelled,
+/// to stop at its next `cancel::checkpoint`.
+pub fn with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Failure> {
+    let Some(timeout) = timeout else {
+        return isolate(f).map_err(Failure::Error);
+    };
+
+    let token = Token::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker_token = token.clone();
+    thread::spawn(move || {
+        let result = isolate(|| cancel::with_token(worker_token, f));
+        // Nobody listens any more if the part timed out.
+        let _ = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(_) => {
+            token.cancel();
+            Err(Failure::TimedOut(timeout))
+        }
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> &str {
+    if payload.is::<Cancelled>() {
+        "cancelled"
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+/// Applies `f` to every item on worker threads, one per core, or on a single
+/// worker when `sequential`. Results keep the order of `items`.
+pub fn map<T: Sync, R: Send>(
+    items: &[T],
+    sequential: bool,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let workers = if sequential {
+        1
+    } else {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    };
+    let next = AtomicUsize::new(0);
+
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers.min(items.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(i) else {
+                            return done;
+                        };
+                        done.push((i, f(item)));
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("worker panicked"))
+            .collect()
+    });
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn panic_becomes_error() {
+        assert_eq!(Ok(3), isolate(|| 1 + 2));
+        assert_eq!(
+            Err("panicked: not yet".to_string()),
+            isolate(|| -> u8 { panic!("not yet") })
+        );
+        assert_eq!(
+            Err("panicked: day 9".to_string()),
+            isolate(|| -> u8 { panic!("day {}", 9) })
+        );
+        assert_eq!(
+            Ok(Err("panicked: inner".to_string())),
+            isolate(|| isolate(|| -> u8 { panic!("inner") }))
+        );
+        // Panics outside `isolate` are loud again afterwards.
+        assert_eq!(0, ISOLATED.get());
+    }
+
+    #[test]
+    fn give_up_after_timeout() {
+        let timeout = Some(Duration::from_millis(20));
+        assert_eq!(Ok(3), with_timeout(timeout, || 1 + 2));
+        assert_eq!(Ok(3), with_timeout(None, || 1 + 2));
+        assert_eq!(
+            Err(Failure::Error("panicked: bad".to_string())),
+            with_timeout(timeout, || -> u8 { panic!("bad") })
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let result = with_timeout(timeout, move || {
+            let stopped = isolate(|| loop {
+                cancel::checkpoint();
+                thread::sleep(Duration::from_millis(1));
+            });
+            sender.send(stopped).unwrap();
+        });
+        assert_eq!(Err(Failure::TimedOut(Duration::from_millis(20))), result);
+        assert_eq!(
+            Err("panicked: cancelled".to_string()),
+            receiver.recv().unwrap()
+        );
+    }
+
+    #[test]
+    fn results_in_order() {
+        let items: Vec<u64> = (0..100).collect();
+        let squares: Vec<u64> = items.iter().map(|n| n * n).collect();
+        assert_eq!(squares, map(&items, false, |n| n * n));
+        assert_eq!(squares, map(&items, true, |n| n * n));
+        assert!(map(&[] as &[u64], false, |n| *n).is_empty());
+    }
+}
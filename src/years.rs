@@ -0,0 +1,32 @@
+pub mod y2025;
+
+use crate::solution::Day;
+
+/// One event's registered days.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2025,
+    days: y2025::days::DAYS,
+}];
+
+pub fn get(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent registered year, the default everywhere.
+pub fn latest() -> &'static Year {
+    YEARS
+        .iter()
+        .max_by_key(|y| y.year)
+        .expect("a year is registered")
+}
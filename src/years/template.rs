@@ -0,0 +1,73 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input;
+use crate::runner::UNSOLVED;
+use crate::solution::Solution;
+use std::fs::File;
+use std::io::{
+    BufRead,
+    BufReader,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Puzzle {
+    #[allow(dead_code)]
+    lines: Vec<String>,
+}
+
+impl Puzzle {
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let lines = input::lines(reader)
+            .map(|line| Ok(line?.1))
+            .collect::<Result<_>>()?;
+
+        Ok(Puzzle { lines })
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_reader(input.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Puzzle {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_1(&self) -> Answer {
+        UNSOLVED.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        UNSOLVED.into()
+    }
+}
+
+#[cfg(test)]
+mod <name> {
+    use super::*;
+
+    // Both parts against `inputs/<year>/<NN>/` are tested from the answers in
+    // `inputs/<year>/answers.toml`, so only the pieces need tests here.
+    #[test]
+    fn parse_lines() {
+        let puzzle = Puzzle::try_from("a\n\nb\n").unwrap();
+        assert_eq!(2, puzzle.lines.len());
+    }
+}
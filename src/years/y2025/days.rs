@@ -0,0 +1,13 @@
+use crate::solution::days;
+
+days! {
+    "y2025-d01" => d01::Document(1),
+    "y2025-d02" => d02::Products(2),
+    "y2025-d03" => d03::Banks(3),
+    "y2025-d04" => d04::Department(4),
+    "y2025-d05" => d05::Database(5),
+    "y2025-d06" => d06::Homework(6),
+    "y2025-d07" => d07::Laboratory(7),
+    "y2025-d08" => d08::Rig(8),
+    "y2025-d09" => d09::Theater(9),
+}
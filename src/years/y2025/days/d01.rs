@@ -0,0 +1,164 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{
+    parse_number,
+    Error,
+    Result,
+};
+use crate::input;
+use crate::solution::Solution;
+use std::fs::File;
+use std::io::{
+    BufRead,
+    BufReader,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Rotation {
+    direction: Direction,
+    steps: i32,
+}
+
+impl Rotation {
+    fn new(direction: Direction, steps: i32) -> Self {
+        Rotation { direction, steps }
+    }
+
+    fn parse(r: &str, line: usize) -> Result<Self> {
+        let direction = match r.chars().next() {
+            Some('L') => Direction::Left,
+            Some('R') => Direction::Right,
+            Some(c) => return Err(Error::unexpected_char(c, line, 1)),
+            None => return Err(Error::missing("rotation", line, 1)),
+        };
+        let steps = parse_number(r, &r[1..], line)?;
+        Ok(Rotation::new(direction, steps))
+    }
+}
+
+#[derive(Debug)]
+pub struct Document {
+    rotations: Vec<Rotation>,
+}
+
+impl Document {
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let rotations: Vec<Rotation> = input::lines(reader)
+            .map(|line| {
+                let (line, r) = line?;
+                Rotation::parse(&r, line)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Document { rotations })
+    }
+}
+
+impl FromStr for Document {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_reader(input.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Document {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Document {
+    fn part_1(&self) -> Answer {
+        let cycle_size = 100;
+        let mut total_zeros = 0;
+        let mut position: i32 = 50;
+        let mut new_position: i32;
+        for rotation in &self.rotations {
+            let step = rotation.steps % cycle_size;
+            match rotation.direction {
+                Direction::Left => {
+                    new_position = position - step;
+                    if position > step {
+                        position = new_position
+                    } else if position < step {
+                        position = cycle_size + new_position
+                    } else {
+                        position = 0;
+                        total_zeros += 1;
+                    }
+                }
+                Direction::Right => {
+                    new_position = position + step;
+                    if new_position < cycle_size {
+                        position = new_position
+                    } else if new_position > cycle_size {
+                        position = new_position - cycle_size
+                    } else {
+                        position = 0;
+                        total_zeros += 1;
+                    }
+                }
+            };
+        }
+        total_zeros.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let cycle_size = 100;
+        let mut total_zeros = 0;
+        let mut position: i32 = 50;
+        let mut direction: i32;
+        for rotation in &self.rotations {
+            direction = match rotation.direction {
+                Direction::Left => -1,
+                Direction::Right => 1,
+            };
+            for step in 0..rotation.steps.abs() {
+                // A single rotation can be huge, so look in every so often
+                // rather than once per rotation.
+                if step % 4096 == 0 {
+                    cancel::checkpoint();
+                }
+                position += direction;
+                if position % cycle_size == 0 {
+                    total_zeros += 1;
+                }
+            }
+        }
+        total_zeros.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_inline() {
+        let document = Document::try_from("L68\nL30\nR48\nL5").unwrap();
+        assert_eq!(1, document.part_1())
+    }
+
+    #[test]
+    fn bad_direction() {
+        let error = "L68\nR30\nX48".parse::<Document>().unwrap_err();
+        assert_eq!(
+            "line 3, column 1: unexpected character 'X'",
+            error.to_string()
+        );
+    }
+}
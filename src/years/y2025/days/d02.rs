@@ -0,0 +1,138 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{
+    column,
+    parse_number,
+    Error,
+    Result,
+};
+use crate::solution::Solution;
+use crate::utils::intervals::IntervalSet;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Products {
+    ids: IntervalSet<u64>,
+}
+
+impl Products {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw_ranges = fs::read_to_string(path)?;
+
+        raw_ranges.parse()
+    }
+}
+
+impl FromStr for Products {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let line = input.trim();
+        let ids = line
+            .split(",")
+            .map(|range| parse_range(line, range))
+            .collect::<Result<_>>()?;
+
+        Ok(Products { ids })
+    }
+}
+
+impl TryFrom<&str> for Products {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Products {
+    fn part_1(&self) -> Answer {
+        invalid_ids(&self.ids, is_valid_id_part_1)
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        invalid_ids(&self.ids, is_valid_id_part_2)
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// Parses `range`, a `xxx-yyy` slice of the input `line`.
+fn parse_range(line: &str, range: &str) -> Result<RangeInclusive<u64>> {
+    let (start_unparsed, end_unparsed) =
+        range.split_once("-").ok_or_else(|| {
+            let end = &range[range.len()..];
+            Error::missing("range end", 1, column(line, end))
+        })?;
+
+    let start: u64 = parse_number(line, start_unparsed, 1)?;
+    let end: u64 = parse_number(line, end_unparsed, 1)?;
+
+    Ok(start..=end)
+}
+
+/// Every id of `ids` that `is_valid_id` rejects, each counted once even if
+/// the input ranges overlap.
+fn invalid_ids<F>(
+    ids: &IntervalSet<u64>,
+    is_valid_id: F,
+) -> impl Iterator<Item = u64>
+where
+    F: Fn(&u64) -> bool,
+{
+    ids.iter().flatten().filter(move |id| {
+        cancel::checkpoint();
+        !is_valid_id(id)
+    })
+}
+
+fn is_valid_id_part_1(id: &u64) -> bool {
+    let s: String = id.to_string();
+    if !s.len().is_multiple_of(2) {
+        return true;
+    }
+    let (left, right) = s.split_at(s.len() / 2);
+    left != right
+}
+
+fn is_valid_id_part_2(id: &u64) -> bool {
+    let s: String = id.to_string();
+    for chunk_size in 1..=(s.len() / 2) {
+        if !s.len().is_multiple_of(chunk_size) {
+            continue;
+        }
+
+        let batches: Vec<String> = s
+            .chars()
+            .collect::<Vec<char>>()
+            .chunks(chunk_size)
+            .map(|c| -> String { c.iter().collect::<String>() })
+            .collect();
+
+        if batches.windows(2).all(|w| w[0] == w[1]) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_missing_range_end() {
+        let error = "11-22,95".parse::<Products>().unwrap_err();
+        assert_eq!("line 1, column 9: missing range end", error.to_string());
+    }
+
+    #[test]
+    fn test_overlapping_ranges_count_once() {
+        let products: Products = "11-22,20-33".parse().unwrap();
+        assert_eq!(11 + 22 + 33, products.part_1());
+    }
+}
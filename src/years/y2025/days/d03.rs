@@ -1,44 +1,89 @@
-use std::fs;
-
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input;
+use crate::solution::Solution;
+use std::fs::File;
+use std::io::{
+    BufRead,
+    BufReader,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
 pub struct Banks {
     rows: Vec<Bank>,
 }
 
+#[derive(Debug)]
 struct Bank {
     batteries: Vec<u64>,
 }
 
 impl Banks {
-    pub fn new(path: &str) -> Self {
-        let rows: Vec<Bank> = fs::read_to_string(path)
-            .expect("File could not be parsed")
-            .trim()
-            .split("\n")
-            .map(|l| Bank::new(l))
-            .collect();
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let rows: Vec<Bank> = input::lines(reader)
+            .map(|line| {
+                let (line, l) = line?;
+                Bank::new(&l).map_err(|e| e.at_line(line))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Banks { rows })
+    }
+}
 
-        Banks { rows }
+impl FromStr for Banks {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_reader(input.as_bytes())
     }
+}
+
+impl TryFrom<&str> for Banks {
+    type Error = Error;
 
-    pub fn part_1(&self) -> u64 {
-        self.rows.iter().map(|b| b.find_largest_joltage()).sum()
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
     }
+}
 
-    pub fn part_2(&self) -> u64 {
+impl Solution for Banks {
+    fn part_1(&self) -> Answer {
+        self.rows
+            .iter()
+            .map(|b| b.find_largest_joltage())
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
         self.rows
             .iter()
             .map(|b| b.find_largest_joltage2(&b.batteries, &12))
-            .sum()
+            .sum::<u64>()
+            .into()
     }
 }
 
 impl Bank {
-    fn new(line: &str) -> Self {
+    fn new(line: &str) -> Result<Self> {
         let batteries = line
             .chars()
-            .map(|c| c.to_digit(10).unwrap() as u64)
-            .collect();
-        Bank { batteries }
+            .enumerate()
+            .map(|(idx, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as u64),
+                None => Err(Error::unexpected_char(c, 1, idx + 1)),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Bank { batteries })
     }
 
     fn find_largest_joltage(&self) -> u64 {
@@ -71,9 +116,13 @@ impl Bank {
     fn find_largest_joltage2(&self, batteries: &[u64], size: &usize) -> u64 {
         let mut max_value = (0, 0);
 
-        for i in 0..(batteries.len() - size + 1) {
-            if batteries[i] > max_value.1 {
-                max_value = (i, batteries[i]);
+        for (i, battery) in batteries
+            .iter()
+            .enumerate()
+            .take(batteries.len() - size + 1)
+        {
+            if *battery > max_value.1 {
+                max_value = (i, *battery);
                 if max_value.1 == 9 {
                     break;
                 }
@@ -103,43 +152,40 @@ mod d03 {
 
     #[test]
     fn part1_case1() {
-        let bank = Bank::new("987654321111111");
+        let bank = Bank::new("987654321111111").unwrap();
         assert_eq!(98, bank.find_largest_joltage());
     }
 
     #[test]
     fn part1_case2() {
-        let bank = Bank::new("811111111111119");
+        let bank = Bank::new("811111111111119").unwrap();
         assert_eq!(89, bank.find_largest_joltage());
     }
 
     #[test]
     fn part1_case3() {
-        let bank = Bank::new("234234234234278");
+        let bank = Bank::new("234234234234278").unwrap();
         assert_eq!(78, bank.find_largest_joltage());
     }
 
     #[test]
     fn part1_case4() {
-        let bank = Bank::new("818181911112111");
+        let bank = Bank::new("818181911112111").unwrap();
         assert_eq!(92, bank.find_largest_joltage());
     }
 
     #[test]
-    fn part1_example() {
-        let banks = Banks::new("src/days/inputs/03/example.txt");
-        assert_eq!(357, banks.part_1())
-    }
-
-    #[test]
-    fn part1() {
-        let banks = Banks::new("src/days/inputs/03/input.txt");
-        assert_eq!(17408, banks.part_1())
+    fn bad_battery() {
+        let error = Banks::try_from("987654321111111\n81111a").unwrap_err();
+        assert_eq!(
+            "line 2, column 6: unexpected character 'a'",
+            error.to_string()
+        );
     }
 
     #[test]
     fn part2_case1() {
-        let bank = Bank::new("987654321111111");
+        let bank = Bank::new("987654321111111").unwrap();
         assert_eq!(
             987654321111,
             bank.find_largest_joltage2(&bank.batteries, &12)
@@ -148,7 +194,7 @@ mod d03 {
 
     #[test]
     fn part2_case2() {
-        let bank = Bank::new("811111111111119");
+        let bank = Bank::new("811111111111119").unwrap();
         assert_eq!(
             811111111119,
             bank.find_largest_joltage2(&bank.batteries, &12)
@@ -157,7 +203,7 @@ mod d03 {
 
     #[test]
     fn part2_case3() {
-        let bank = Bank::new("234234234234278");
+        let bank = Bank::new("234234234234278").unwrap();
         assert_eq!(
             434234234278,
             bank.find_largest_joltage2(&bank.batteries, &12)
@@ -166,16 +212,10 @@ mod d03 {
 
     #[test]
     fn part2_case4() {
-        let bank = Bank::new("818181911112111");
+        let bank = Bank::new("818181911112111").unwrap();
         assert_eq!(
             888911112111,
             bank.find_largest_joltage2(&bank.batteries, &12)
         );
     }
-
-    #[test]
-    fn part2() {
-        let banks = Banks::new("src/days/inputs/03/input.txt");
-        assert_eq!(172740584266849, banks.part_2())
-    }
 }
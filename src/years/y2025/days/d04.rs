@@ -0,0 +1,104 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::solution::Solution;
+use crate::utils::grid::{
+    Grid,
+    Position,
+};
+use std::fs;
+use std::str::FromStr;
+
+/// The printing department's floor, where a cell holds a roll of paper or
+/// nothing.
+#[derive(Debug)]
+pub struct Department {
+    rolls: Grid<bool>,
+}
+
+impl Department {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw = fs::read_to_string(path)?;
+
+        raw.parse()
+    }
+
+    fn accessible(rolls: &Grid<bool>, position: Position) -> bool {
+        rolls[position]
+            && rolls
+                .neighbours_8(position)
+                .filter(|neighbour| rolls[*neighbour])
+                .count()
+                < 4
+    }
+}
+
+impl FromStr for Department {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let rolls = Grid::parse(input, |c| match c {
+            '.' => Some(false),
+            '@' => Some(true),
+            _ => None,
+        })?;
+
+        Ok(Department { rolls })
+    }
+}
+
+impl TryFrom<&str> for Department {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Department {
+    fn part_1(&self) -> Answer {
+        self.rolls
+            .positions()
+            .filter(|position| Self::accessible(&self.rolls, *position))
+            .count()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut rolls = self.rolls.clone();
+        let mut count = 0;
+
+        loop {
+            let removed: Vec<Position> = rolls
+                .positions()
+                .filter(|position| Self::accessible(&rolls, *position))
+                .collect();
+            if removed.is_empty() {
+                break;
+            }
+
+            count += removed.len();
+            for position in removed {
+                rolls[position] = false;
+            }
+        }
+
+        count.into()
+    }
+}
+
+#[cfg(test)]
+mod d04 {
+    use super::*;
+
+    #[test]
+    fn unrecognized_character() {
+        let error = Department::try_from("..@@.\n@@x..").unwrap_err();
+        assert_eq!(
+            "line 2, column 3: unexpected character 'x'",
+            error.to_string()
+        );
+    }
+}
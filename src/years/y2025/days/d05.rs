@@ -0,0 +1,111 @@
+use crate::answer::Answer;
+use crate::error::{
+    parse_number,
+    Error,
+    Result,
+};
+use crate::solution::Solution;
+use crate::utils::intervals::IntervalSet;
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Database {
+    fresh: IntervalSet<u64>,
+    ids: Vec<u64>,
+}
+
+impl Database {
+    pub fn new(path: &str) -> Result<Self> {
+        let binding = fs::read_to_string(path)?;
+
+        binding.parse()
+    }
+}
+
+impl FromStr for Database {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let input = input.trim_end();
+        let (input_ranges, input_ids) =
+            input.split_once("\n\n").ok_or_else(|| {
+                Error::missing("ingredient ids", input.lines().count() + 1, 1)
+            })?;
+
+        let ranges: Vec<_> = input_ranges
+            .lines()
+            .enumerate()
+            .map(|(idx, r)| {
+                let line = idx + 1;
+                let (low, high) = r.split_once("-").ok_or_else(|| {
+                    Error::missing("range end", line, r.chars().count() + 1)
+                })?;
+                let start: u64 = parse_number(r, low, line)?;
+                let end: u64 = parse_number(r, high, line)?;
+                Ok(start..=end)
+            })
+            .collect::<Result<_>>()?;
+
+        let first_id_line = ranges.len() + 2;
+        let ids: Vec<u64> = input_ids
+            .lines()
+            .enumerate()
+            .map(|(idx, r)| parse_number(r, r, first_id_line + idx))
+            .collect::<Result<_>>()?;
+
+        Ok(Database {
+            fresh: ranges.into_iter().collect(),
+            ids,
+        })
+    }
+}
+
+impl TryFrom<&str> for Database {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Database {
+    fn part_1(&self) -> Answer {
+        self.ids
+            .iter()
+            .filter(|id| self.fresh.contains(id))
+            .count()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.fresh.len().into()
+    }
+}
+
+#[cfg(test)]
+mod d05 {
+    use super::*;
+
+    #[test]
+    fn test_missing_ids() {
+        let error = "3-5\n10-14\n".parse::<Database>().unwrap_err();
+        assert_eq!(
+            "line 3, column 1: missing ingredient ids",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_bad_id() {
+        let error = "3-5\n10-14\n\n1\n5a".parse::<Database>().unwrap_err();
+        assert_eq!("line 5, column 1: bad number \"5a\"", error.to_string());
+    }
+
+    #[test]
+    fn test_overlapping_ranges_merge() {
+        let database: Database =
+            "3-5\n10-14\n16-20\n12-18\n\n1".parse().unwrap();
+        assert_eq!(&[3..=5, 10..=20], database.fresh.ranges());
+    }
+}
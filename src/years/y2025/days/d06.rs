@@ -0,0 +1,259 @@
+use crate::answer::Answer;
+use crate::error::{
+    column,
+    parse_number,
+    Error,
+    Result,
+};
+use crate::solution::Solution;
+use regex::Regex;
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Homework {
+    worksheet: Worksheet,
+    worksheet2: Worksheet2,
+}
+
+#[derive(Debug)]
+pub struct Worksheet {
+    exercises: Vec<(Vec<u64>, Operation)>,
+}
+
+#[derive(Debug)]
+pub struct Worksheet2 {
+    exercises: Vec<(u64, Option<Operation>)>,
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Worksheet {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw_worksheet = fs::read_to_string(path)?;
+
+        raw_worksheet.parse()
+    }
+
+    pub fn part_1(&self) -> u64 {
+        self.exercises
+            .iter()
+            .map(|e| {
+                e.0.iter()
+                    .copied()
+                    .reduce(|acc, n| match e.1 {
+                        Operation::Add => acc + n,
+                        Operation::Multiply => acc * n,
+                    })
+                    .unwrap()
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Worksheet {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let re = Regex::new(r"[^\S\n]+").unwrap();
+        let lines: Vec<&str> = input.trim_end().lines().collect();
+        let strings: Vec<Vec<&str>> =
+            lines.iter().map(|l| re.split(l.trim()).collect()).collect();
+
+        let width = strings
+            .first()
+            .ok_or_else(|| Error::missing("worksheet", 1, 1))?
+            .len();
+
+        let mut strings_transposed: Vec<Vec<(usize, &str)>> = Vec::new();
+        for j in 0..width {
+            let mut row = Vec::new();
+            for (i, line) in strings.iter().enumerate() {
+                let token = line.get(j).ok_or_else(|| {
+                    Error::missing("problem", i + 1, lines[i].len() + 1)
+                })?;
+                row.push((i, *token));
+            }
+            strings_transposed.push(row);
+        }
+
+        let exercises: Vec<(Vec<u64>, Operation)> = strings_transposed
+            .iter()
+            .map(|l| {
+                let numbers: Vec<u64> = l[0..l.len() - 1]
+                    .iter()
+                    .map(|(i, n)| parse_number(lines[*i], n, i + 1))
+                    .collect::<Result<_>>()?;
+
+                let (i, token) = *l.last().unwrap();
+                let operation: Operation = match token {
+                    "+" => Operation::Add,
+                    "*" => Operation::Multiply,
+                    _ => {
+                        return Err(Error::unexpected_char(
+                            token.chars().next().unwrap_or(' '),
+                            i + 1,
+                            column(lines[i], token),
+                        ));
+                    }
+                };
+
+                Ok((numbers, operation))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Worksheet { exercises })
+    }
+}
+
+impl TryFrom<&str> for Worksheet {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Worksheet2 {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw_worksheet = fs::read_to_string(path)?;
+
+        raw_worksheet.parse()
+    }
+
+    pub fn solve(&self) -> u64 {
+        let mut total: u64 = 0;
+        let mut buffer: Vec<u64> = Vec::new();
+
+        for (number, operation) in &self.exercises {
+            buffer.push(*number);
+            if let Some(o) = operation {
+                total += match o {
+                    Operation::Add => buffer.iter().sum::<u64>(),
+                    Operation::Multiply => buffer.iter().product::<u64>(),
+                };
+                buffer.clear();
+            }
+        }
+
+        total
+    }
+}
+
+impl FromStr for Worksheet2 {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let chars: Vec<Vec<char>> =
+            input.lines().map(|l| l.chars().collect()).collect();
+        let operation_line = chars.len();
+        let width = chars.iter().map(|l| l.len()).max().unwrap_or(0);
+
+        let mut chars_transposed: Vec<(usize, Vec<char>)> = Vec::new();
+        for j in 0..width {
+            let mut row = Vec::new();
+            for line in &chars {
+                row.push(line.get(j).copied().unwrap_or(' '));
+            }
+            chars_transposed.push((j, row));
+        }
+        chars_transposed.reverse();
+
+        let exercises: Vec<(u64, Option<Operation>)> = chars_transposed
+            .iter()
+            .filter(|(_, l)| l.iter().any(|c| *c != ' '))
+            .map(|(j, l)| {
+                let digits = &l[..l.len() - 1];
+                let text = digits.iter().collect::<String>();
+                let number: u64 = text.trim().parse().map_err(|_| {
+                    // Point at the first character that is not a digit, or
+                    // at the start of the number if they all are.
+                    let row = digits
+                        .iter()
+                        .position(|c| !c.is_ascii_digit() && *c != ' ')
+                        .or_else(|| digits.iter().position(|c| *c != ' '))
+                        .unwrap_or(0);
+                    Error::bad_number(text.trim(), row + 1, j + 1)
+                })?;
+
+                let operation: Option<Operation> = match l[l.len() - 1] {
+                    '+' => Some(Operation::Add),
+                    '*' => Some(Operation::Multiply),
+                    ' ' => None,
+                    c => {
+                        return Err(Error::unexpected_char(
+                            c,
+                            operation_line,
+                            j + 1,
+                        ));
+                    }
+                };
+
+                Ok((number, operation))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Worksheet2 { exercises })
+    }
+}
+
+impl TryFrom<&str> for Worksheet2 {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl FromStr for Homework {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Ok(Homework {
+            worksheet: input.parse()?,
+            worksheet2: input.parse()?,
+        })
+    }
+}
+
+impl TryFrom<&str> for Homework {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Homework {
+    fn part_1(&self) -> Answer {
+        self.worksheet.part_1().into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.worksheet2.solve().into()
+    }
+}
+
+#[cfg(test)]
+mod d06 {
+    use super::*;
+
+    #[test]
+    fn test_unknown_operation() {
+        let error = "123 328\n 45 64\n*   -".parse::<Worksheet>().unwrap_err();
+        assert_eq!(
+            "line 3, column 5: unexpected character '-'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_part2_bad_number() {
+        let error = "12 4\n3x 5\n+  *\n".parse::<Worksheet2>().unwrap_err();
+        assert_eq!("line 2, column 2: bad number \"2x\"", error.to_string());
+    }
+}
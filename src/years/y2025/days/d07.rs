@@ -0,0 +1,335 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::solution::{
+    Solution,
+    Variant,
+};
+use crate::utils::dag::{
+    Dag,
+    DagError,
+};
+use crate::utils::grid::Grid;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Laboratory {
+    manifold: TachyonManifold,
+    graph: Graph,
+}
+
+#[derive(Debug)]
+pub struct TachyonManifold {
+    diagram: Grid<Obstacle>,
+    beams: Vec<Beam>,
+}
+
+/// The splitters beams can go through as a DAG, from the beam start down to
+/// one sink per column below the diagram.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    dag: Dag,
+    start: usize,
+    /// Paths from `start` to the bottom, counted while parsing so a diagram
+    /// with more of them than a u128 holds is refused there.
+    timelines: u128,
+}
+
+#[derive(Clone, Debug)]
+enum Obstacle {
+    Space,
+    Splitter,
+}
+
+#[derive(Debug)]
+struct Beam {
+    position: usize,
+}
+
+impl TachyonManifold {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw = fs::read_to_string(path)?;
+
+        raw.parse()
+    }
+
+    pub fn part_1(&self) -> usize {
+        let diagram_range = 0..self.diagram.width();
+        let mut splits = 0;
+        let mut beam_positions = self.get_beam_postions();
+        let mut next_beam_positions: HashSet<usize>;
+        let mut left: usize = 0;
+        let mut right: usize = 0;
+
+        for line in self.diagram.rows() {
+            next_beam_positions = HashSet::new();
+            beam_positions.iter().for_each(|b| {
+                match line[*b] {
+                    Obstacle::Space => {
+                        next_beam_positions.insert(*b);
+                    }
+                    Obstacle::Splitter => {
+                        splits += 1;
+
+                        left = b - 1;
+                        right = b + 1;
+
+                        if diagram_range.contains(&left) {
+                            next_beam_positions.insert(left);
+                        }
+
+                        if diagram_range.contains(&right) {
+                            next_beam_positions.insert(right);
+                        }
+                    }
+                };
+            });
+            beam_positions = next_beam_positions;
+        }
+
+        splits
+    }
+
+    fn get_beam_postions(&self) -> HashSet<usize> {
+        self.beams.iter().map(|b| b.position).collect()
+    }
+}
+
+impl FromStr for TachyonManifold {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let first_line = input.lines().next().unwrap_or("");
+        let beam_start = first_line
+            .find("S")
+            .ok_or_else(|| Error::missing("beam start 'S'", 1, 1))?;
+
+        let diagram = Grid::parse(input, |c| match c {
+            '.' | 'S' => Some(Obstacle::Space),
+            '^' => Some(Obstacle::Splitter),
+            _ => None,
+        })?;
+
+        let beams = vec![Beam::new(beam_start)];
+
+        Ok(TachyonManifold { diagram, beams })
+    }
+}
+
+impl TryFrom<&str> for TachyonManifold {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Beam {
+    fn new(position: usize) -> Self {
+        Beam { position }
+    }
+}
+
+impl fmt::Display for Obstacle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Obstacle::Space => write!(f, "."),
+            Obstacle::Splitter => write!(f, "^"),
+        }
+    }
+}
+
+impl Graph {
+    pub fn new(path: &str) -> Result<Self> {
+        let raw = fs::read_to_string(path)?;
+
+        raw.parse()
+    }
+
+    /// The node first met going down `column` from `row`, which the beam
+    /// leaving it reaches.
+    fn first_below(
+        nodes: &Grid<Option<usize>>,
+        row: usize,
+        column: usize,
+    ) -> Option<usize> {
+        nodes.column(column).skip(row).find_map(|node| *node)
+    }
+
+    /// How many timelines a single beam ends up in, one per path from the
+    /// start to the bottom.
+    pub fn part_2(&self) -> u128 {
+        self.timelines
+    }
+
+    /// `part_2` worked out by the graph itself: timelines are pushed from
+    /// each node to its children in topological order and summed where they
+    /// leave the bottom, so it checks the `Dag`'s own path counting.
+    pub fn propagate(&self) -> Result<u128> {
+        let mut timelines = vec![0u128; self.dag.len()];
+        timelines[self.start] = 1;
+        let mut bottom = 0u128;
+        for node in self.dag.topological_order()? {
+            let count = timelines[node];
+            let children = self.dag.children(node);
+            if children.is_empty() {
+                bottom = bottom.checked_add(count).ok_or(DagError::Overflow)?;
+            }
+            for child in children {
+                timelines[*child] = timelines[*child]
+                    .checked_add(count)
+                    .ok_or(DagError::Overflow)?;
+            }
+        }
+        Ok(bottom)
+    }
+}
+
+impl FromStr for Graph {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let first_line = input.lines().next().unwrap_or("");
+        let start_column = first_line
+            .chars()
+            .position(|c| c == 'S')
+            .ok_or_else(|| Error::missing("beam start 'S'", 1, 1))?;
+
+        let diagram =
+            Grid::parse(input, |c| matches!(c, 'S' | '^' | '.').then_some(c))?;
+
+        let mut dag = Dag::default();
+        let cells = diagram
+            .iter()
+            .map(|(_, c)| (*c != '.').then(|| dag.add_node()))
+            .collect();
+        let mut nodes = Grid::new(diagram.width(), diagram.height(), cells);
+        nodes.push_row((0..diagram.width()).map(|_| Some(dag.add_node())));
+
+        for ((row, column), c) in diagram.iter() {
+            let Some(node) = nodes[(row, column)] else {
+                continue;
+            };
+            // Beams leave a node downwards, never along its own row, so
+            // neighbouring splitters do not lead into each other.
+            let children: Vec<usize> = match c {
+                'S' => vec![column],
+                _ => [column.checked_sub(1), Some(column + 1)]
+                    .into_iter()
+                    .flatten()
+                    .filter(|column| *column < diagram.width())
+                    .collect(),
+            }
+            .into_iter()
+            .filter_map(|column| Self::first_below(&nodes, row + 1, column))
+            .collect();
+            for child in children {
+                dag.add_edge(node, child);
+            }
+        }
+
+        let start = nodes[(0, start_column)].unwrap();
+        let timelines = dag.count_paths([start])?;
+        Ok(Graph {
+            dag,
+            start,
+            timelines,
+        })
+    }
+}
+
+impl TryFrom<&str> for Graph {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl FromStr for Laboratory {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Ok(Laboratory {
+            manifold: input.parse()?,
+            graph: input.parse()?,
+        })
+    }
+}
+
+impl TryFrom<&str> for Laboratory {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Laboratory {
+    const VARIANTS: &'static [Variant<Self>] = &[Variant {
+        part: 2,
+        name: "propagate",
+        solve: |lab| match lab.graph.propagate() {
+            Ok(timelines) => timelines.into(),
+            Err(e) => e.to_string().into(),
+        },
+    }];
+
+    fn part_1(&self) -> Answer {
+        self.manifold.part_1().into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.graph.part_2().into()
+    }
+}
+
+#[cfg(test)]
+mod d07 {
+    use super::*;
+
+    #[test]
+    fn test_missing_beam_start() {
+        let error = "...\n.^.".parse::<TachyonManifold>().unwrap_err();
+        assert_eq!(
+            "line 1, column 1: missing beam start 'S'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_character() {
+        let error = ".S.\n...\n.#.".parse::<Graph>().unwrap_err();
+        assert_eq!(
+            "line 3, column 2: unexpected character '#'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_adjacent_splitters() {
+        // Each splitter sends its beams below the other, not into it.
+        let graph = ".S.\n.^^\n...".parse::<Graph>().unwrap();
+        assert_eq!(2, graph.part_2());
+        assert_eq!(2, graph.propagate().unwrap());
+    }
+
+    #[test]
+    fn test_too_many_timelines() {
+        // Every row of splitters doubles the timelines.
+        let rows = 130;
+        let mut diagram =
+            format!("{}S{}\n", ".".repeat(rows), ".".repeat(rows));
+        for _ in 0..rows {
+            diagram += &"^".repeat(2 * rows + 1);
+            diagram.push('\n');
+        }
+        let error = diagram.parse::<Graph>().unwrap_err();
+        assert_eq!("more paths than fit in a u128", error.to_string());
+    }
+}
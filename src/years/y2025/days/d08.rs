@@ -0,0 +1,155 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input;
+use crate::solution::Solution;
+use crate::utils::dsu::DisjointSet;
+use crate::utils::geom::Point3;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{
+    BufRead,
+    BufReader,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Rig {
+    junction_boxes: Vec<JunctionBox>,
+    distances: BTreeMap<(usize, usize), i64>,
+    /// How many of the closest pairs part 1 connects.
+    connections: usize,
+}
+
+#[derive(Debug)]
+struct JunctionBox {
+    location: Point3<i64>,
+}
+
+impl Rig {
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let junction_boxes: Vec<JunctionBox> = input::lines(reader)
+            .map(|line| {
+                let (line, l) = line?;
+                let location = Point3::parse(&l, line)?;
+                Ok(JunctionBox { location })
+            })
+            .collect::<Result<_>>()?;
+
+        let mut distances = BTreeMap::new();
+        for i in 0..junction_boxes.len() {
+            for j in (i + 1)..junction_boxes.len() {
+                let distance = junction_boxes[i]
+                    .location
+                    .distance_squared(&junction_boxes[j].location);
+                distances.insert((i, j), distance);
+            }
+        }
+
+        Ok(Rig {
+            connections: Self::connections(junction_boxes.len())?,
+            junction_boxes,
+            distances,
+        })
+    }
+
+    pub fn connect_closest(&self, top_n: usize) -> usize {
+        let mut circuits = DisjointSet::new(self.junction_boxes.len());
+        for (id1, id2) in self.sorted_pairs().into_iter().take(top_n) {
+            circuits.union(id1, id2);
+        }
+
+        let mut sizes: Vec<usize> =
+            circuits.components().map(|c| c.len()).collect();
+        sizes.sort_by_key(|size| std::cmp::Reverse(*size));
+        sizes.iter().take(3).product()
+    }
+
+    /// The example connects the 10 closest pairs of its 20 junction boxes,
+    /// real inputs the 1000 closest of their 1000. The puzzle only says so in
+    /// its text, so any other input is refused rather than guessed at.
+    fn connections(boxes: usize) -> Result<usize> {
+        match boxes {
+            20 => Ok(10),
+            1000 => Ok(1000),
+            n => Err(Error::Unsupported(format!(
+                "no known number of pairs to connect for {n} junction boxes, \
+                 only for 20 or 1000"
+            ))),
+        }
+    }
+
+    fn sorted_pairs(&self) -> Vec<(usize, usize)> {
+        let mut sorted_keys: Vec<(&(usize, usize), &i64)> =
+            self.distances.iter().collect();
+
+        sorted_keys.sort_by(|a, b| a.1.cmp(b.1));
+        sorted_keys.into_iter().map(|(ids, _)| *ids).collect()
+    }
+}
+
+impl FromStr for Rig {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_reader(input.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Rig {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Rig {
+    fn part_1(&self) -> Answer {
+        self.connect_closest(self.connections).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut circuits = DisjointSet::new(self.junction_boxes.len());
+        let (id1, id2) = self
+            .sorted_pairs()
+            .into_iter()
+            .find(|&(id1, id2)| {
+                circuits.union(id1, id2) && circuits.component_count() == 1
+            })
+            .unwrap();
+        (self.junction_boxes[id1].location.x
+            * self.junction_boxes[id2].location.x)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod d08 {
+    use super::*;
+
+    #[test]
+    fn test_missing_coordinate() {
+        let error = Rig::try_from("162,817,812\n57,618").unwrap_err();
+        assert_eq!("line 2, column 7: missing z coordinate", error.to_string());
+    }
+
+    #[test]
+    fn test_connections_by_input_size() {
+        let boxes =
+            |n: usize| (0..n).map(|x| format!("{x},0,0\n")).collect::<String>();
+        assert_eq!(10, Rig::try_from(&*boxes(20)).unwrap().connections);
+        let error = Rig::try_from(&*boxes(999)).unwrap_err();
+        assert_eq!(
+            "no known number of pairs to connect for 999 junction boxes, only \
+             for 20 or 1000",
+            error.to_string()
+        );
+    }
+}
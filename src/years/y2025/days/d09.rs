@@ -0,0 +1,101 @@
+use crate::answer::Answer;
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::input;
+use crate::runner::UNSOLVED;
+use crate::solution::Solution;
+use crate::utils::geom::{
+    Bounds,
+    Point2,
+};
+use std::fs::File;
+use std::io::{
+    BufRead,
+    BufReader,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Theater {
+    points: Vec<Point>,
+}
+
+type Point = Point2<u64>;
+
+/// How many tiles the rectangle with opposite corners `p1` and `p2` covers,
+/// edges included.
+fn area(p1: &Point, p2: &Point) -> u64 {
+    let size = Bounds::of([*p1, *p2]).unwrap().size();
+    (size.x + 1) * (size.y + 1)
+}
+
+impl Theater {
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let points: Vec<Point> = input::lines(reader)
+            .map(|line| {
+                let (line, l) = line?;
+                Point::parse(&l, line)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Theater { points })
+    }
+}
+
+impl FromStr for Theater {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_reader(input.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Theater {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+impl Solution for Theater {
+    fn part_1(&self) -> Answer {
+        let points = &self.points;
+
+        let mut max_area = 0;
+        for (idx, p1) in points.iter().enumerate() {
+            for p2 in &points[(idx + 1)..] {
+                max_area = max_area.max(area(p1, p2));
+            }
+        }
+        max_area.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        UNSOLVED.into()
+    }
+}
+
+#[cfg(test)]
+mod d09 {
+    use super::*;
+
+    #[test]
+    fn test_part_1_reader() {
+        let reader = "7,1\n11,1\n11,7\n9,7\n".as_bytes();
+        let output = Theater::from_reader(reader).unwrap().part_1();
+        assert_eq!(35, output);
+    }
+
+    #[test]
+    fn test_bad_coordinate() {
+        let error = Theater::try_from("7,1\n11,-7").unwrap_err();
+        assert_eq!("line 2, column 4: bad number \"-7\"", error.to_string());
+    }
+}